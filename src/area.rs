@@ -0,0 +1,141 @@
+use crate::{
+    i18n::Locale,
+    quantity::{self, LinearUnit, ParseUnitError, Quantity},
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+};
+use std::{fmt, str::FromStr};
+
+/// The `area` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "area",
+    "Convert from one area unit to another. Supports m², km², ft², yd², acres, and hectares.",
+)
+.options(&[
+    OptionSpec::string("value", "Original value (e.g. '3 acres', '1000 sq ft')."),
+    OptionSpec::string(
+        "target",
+        "The unit to target. (e.g 'ha' [hectares], 'm2' [square metres]).",
+    ),
+]);
+
+/// The `area` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec =
+    SubcommandSpec::new("area", "Convert a number from one area unit to another.")
+        .options(&quantity::typed_options::<AreaUnit>());
+
+pub fn run(input: String, target: String) -> String { quantity::run::<AreaUnit>(input, target) }
+
+pub fn convert(input: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert::<AreaUnit>(input, target, locale)
+}
+
+pub fn convert_value(value: f64, unit: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert_value::<AreaUnit>(value, unit, target, locale)
+}
+
+/// An area, stored in square metres.
+pub type Area = Quantity<AreaUnit>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AreaUnit
+{
+    SquareMetre,
+    SquareKilometre,
+    SquareFoot,
+    SquareYard,
+    Acre,
+    Hectare,
+}
+
+impl LinearUnit for AreaUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::SquareMetre,
+        Self::SquareKilometre,
+        Self::SquareFoot,
+        Self::SquareYard,
+        Self::Acre,
+        Self::Hectare,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::SquareMetre => 1.0,
+            Self::SquareKilometre => 1e6,
+            Self::SquareFoot => 0.09290304,
+            Self::SquareYard => 0.83612736,
+            Self::Acre => 4046.8564224,
+            Self::Hectare => 1e4,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::SquareMetre => "m²",
+            Self::SquareKilometre => "km²",
+            Self::SquareFoot => "ft²",
+            Self::SquareYard => "yd²",
+            Self::Acre => "ac",
+            Self::Hectare => "ha",
+        }
+    }
+}
+
+impl FromStr for AreaUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "m²" | "m2" | "m^2" | "sq m" | "square metre" | "square metres" | "square meter"
+            | "square meters" => Self::SquareMetre,
+            "km²" | "km2" | "km^2" | "sq km" | "square kilometre" | "square kilometres"
+            | "square kilometer" | "square kilometers" => Self::SquareKilometre,
+            "ft²" | "ft2" | "ft^2" | "sq ft" | "square foot" | "square feet" => Self::SquareFoot,
+            "yd²" | "yd2" | "yd^2" | "sq yd" | "square yard" | "square yards" => Self::SquareYard,
+            "ac" | "acre" | "acres" => Self::Acre,
+            "ha" | "hectare" | "hectares" => Self::Hectare,
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for AreaUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_acres_to_hectares()
+    {
+        assert_eq!(
+            run("3 acres".to_string(), "ha".to_string()),
+            "3.00 Acre(s) [ac] -> 1.21 Hectare(s) [ha]"
+        );
+    }
+
+    #[test]
+    fn test_square_feet_to_square_metres()
+    {
+        let mut area = Area::from_str("1000 sq ft").unwrap();
+        area.into_unit(AreaUnit::SquareMetre);
+        assert_eq!(area.to_string(), "92.90 Square metre(s) [m²]");
+    }
+}
@@ -0,0 +1,336 @@
+use crate::{
+    date::{Date, DateOrder, ParseDateError},
+    duration::humanise,
+    i18n::Locale,
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+    time::{format_duration, ParseTimeError, Time},
+    timestamp::{Timestamp, TimestampStyle},
+    timezone::{TimeZoneError, Zone},
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `countdown` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "countdown",
+    "Find how long it is until a time, day of the week, or date.",
+)
+.details("Find how long it is until a time, day of the week, or date, with a Discord timestamp counting down to it")
+.options(&[
+    OptionSpec::string("target", "e.g. '6pm Friday UTC', 'Dec 25', 'next Monday at 9am', '2024-01-01 00:00 EST'.")
+        .details("When to count down to, optionally followed by a zone (e.g. '6pm Friday UTC', 'Dec 25', 'next Monday at 9am', '2024-01-01 00:00 EST')"),
+    OptionSpec::string("zone", "The time zone, if it isn't given with the target (e.g. 'Tokyo'). Defaults to your zone, or UTC.")
+        .optional()
+        .details("The time zone, if it isn't given with the target (e.g. 'Tokyo', 'America/New_York'). Defaults to your preferred zone, or UTC"),
+]);
+
+pub fn run(target: String, zone: Option<String>) -> String
+{
+    convert(target, zone, Locale::English).to_string()
+}
+
+/// Like [`run`], but tells errors apart from the countdown, written in
+/// `locale`.
+pub fn convert(target: String, zone: Option<String>, locale: Locale) -> Reply
+{
+    let zone = match zone.map(|x| Zone::from_str(&x)).transpose()
+    {
+        Ok(x) => x.unwrap_or(Zone::Region(Tz::UTC)),
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    match Countdown::new(&target, zone, Utc::now())
+    {
+        Ok(x) => Reply::Text(x.text(locale)),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum CountdownError
+{
+    Unrecognised(String),
+    Time(#[from] ParseTimeError),
+    Date(#[from] ParseDateError),
+    Zone(#[from] TimeZoneError),
+}
+
+impl CountdownError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::Unrecognised(target) =>
+            {
+                locale.text("error.countdown.unrecognised", &[("target", target)])
+            }
+            Self::Time(e) => e.text(locale),
+            Self::Date(e) => e.text(locale),
+            Self::Zone(e) => e.text(locale),
+        }
+    }
+}
+
+/// When a countdown ends, before it's been placed in a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Moment
+{
+    /// A date, which is next year's if no year was given and this year's has
+    /// passed
+    Date(Date),
+
+    /// The next of a day of the week, at midnight if no time was given
+    Weekday(Weekday, Option<Time>),
+
+    /// The next time this time comes around
+    Time(Time),
+}
+
+impl FromStr for Moment
+{
+    type Err = CountdownError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        if let Ok(date) = Date::parse(s, DateOrder::default())
+        {
+            return Ok(Self::Date(date));
+        }
+
+        // Ignore filler words (e.g. 'next Friday at 6pm')
+        let words: Vec<&str> = s
+            .split_whitespace()
+            .map(|x| x.trim_end_matches(','))
+            .filter(|x| !["next", "this", "on", "at"].contains(&&*x.to_lowercase()))
+            .collect();
+        let weekday = words.iter().position(|x| Weekday::from_str(x).is_ok());
+        if let Some(i) = weekday
+        {
+            let rest: Vec<&str> = [&words[..i], &words[i + 1..]].concat();
+            let time = if rest.is_empty()
+            {
+                None
+            }
+            else
+            {
+                Some(Time::from_str(&rest.join(" "))?)
+            };
+            return Ok(Self::Weekday(Weekday::from_str(words[i]).unwrap(), time));
+        }
+
+        match Time::from_str(&words.join(" "))
+        {
+            Ok(time) => Ok(Self::Time(time)),
+            Err(_) => Err(CountdownError::Unrecognised(s.trim().to_string())),
+        }
+    }
+}
+
+/// The time left until a moment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Countdown
+{
+    /// When the countdown ends, in UTC
+    pub end: NaiveDateTime,
+    pub zone: Zone,
+    pub remaining: Duration,
+}
+
+impl Countdown
+{
+    /// Count down from `now` to `target` (e.g. '6pm Friday', 'Dec 25'). A zone
+    /// can be given at the end of the target (e.g. '6pm Friday EST'), otherwise
+    /// it's in `zone`.
+    pub fn new(target: &str, zone: Zone, now: DateTime<Utc>) -> Result<Self, CountdownError>
+    {
+        let words: Vec<&str> = target.split_whitespace().collect();
+
+        // Take the longest zone at the end that leaves something to count to
+        let split = (1..words.len().min(4))
+            .rev()
+            .filter_map(|i| {
+                let zone = Zone::from_str(&words[words.len() - i..].join(" ")).ok()?;
+                let moment = Moment::from_str(&words[..words.len() - i].join(" ")).ok()?;
+                Some((moment, zone))
+            })
+            .next();
+        let (moment, zone) = match split
+        {
+            Some(x) => x,
+            None => (Moment::from_str(target)?, zone),
+        };
+
+        let now = now.naive_utc();
+        let today = zone.localise(now).0;
+        let end = match moment
+        {
+            Moment::Date(date) if date.has_offset => date.datetime.naive_utc(),
+            Moment::Date(date) if date.has_year => zone.to_utc(date.datetime.naive_local())?,
+            Moment::Date(date) =>
+            {
+                // This year's date, or the next one if it has passed, which
+                // may be a few years away for the 29th of February
+                let local = date.datetime.naive_local();
+                let mut end = None;
+                for year in today.year()..=today.year() + 8
+                {
+                    if let Some(local) = local.with_year(year)
+                    {
+                        let utc = zone.to_utc(local)?;
+                        if utc > now
+                        {
+                            end = Some(utc);
+                            break;
+                        }
+                    }
+                }
+                end.ok_or_else(|| CountdownError::Unrecognised(target.trim().to_string()))?
+            }
+            Moment::Weekday(weekday, time) =>
+            {
+                let days = (7 + weekday.num_days_from_monday() as i64
+                    - today.weekday().num_days_from_monday() as i64)
+                    % 7;
+                let time = time.map(|x| x.as_naive()).unwrap_or_default();
+                let end = zone.to_utc((today.date() + Duration::days(days)).and_time(time))?;
+                if end > now
+                {
+                    end
+                }
+                else
+                {
+                    zone.to_utc((today.date() + Duration::days(days + 7)).and_time(time))?
+                }
+            }
+            Moment::Time(time) =>
+            {
+                let end = zone.to_utc(today.date().and_time(time.as_naive()))?;
+                if end > now
+                {
+                    end
+                }
+                else
+                {
+                    zone.to_utc((today.date() + Duration::days(1)).and_time(time.as_naive()))?
+                }
+            }
+        };
+
+        Ok(Self {
+            end,
+            zone,
+            remaining: Duration::seconds((end - now).num_seconds()),
+        })
+    }
+
+    /// A Discord timestamp for when the countdown ends.
+    pub fn timestamp(&self) -> Timestamp { Timestamp(Utc.from_utc_datetime(&self.end).timestamp()) }
+
+    /// The countdown as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        let (local, abbreviation) = self.zone.localise(self.end);
+        let name = |kind: &str, format: &str| {
+            locale.text(
+                &format!("{kind}.{}", local.format(format)).to_lowercase(),
+                &[],
+            )
+        };
+        let date = locale.text(
+            "countdown.date",
+            &[
+                ("weekday", &name("weekday", "%A")),
+                ("day", &local.format("%-d").to_string()),
+                ("month", &name("month", "%B")),
+                ("year", &local.format("%Y").to_string()),
+                ("time", &local.format("%H:%M:%S").to_string()),
+            ],
+        );
+        let when = format!("{date} {}", self.zone.describe(&abbreviation));
+        let (key, remaining) = if self.remaining < Duration::zero()
+        {
+            ("countdown.past", -self.remaining)
+        }
+        else
+        {
+            ("countdown.future", self.remaining)
+        };
+        let tag = self.timestamp().tag(TimestampStyle::Relative);
+        format!(
+            "{}\nDiscord: `{tag}` -> {tag}",
+            locale.text(
+                key,
+                &[
+                    ("when", &when),
+                    ("words", &humanise(remaining, locale)),
+                    ("duration", &format_duration(remaining)),
+                ],
+            )
+        )
+    }
+}
+
+impl fmt::Display for Countdown
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn countdown(target: &str) -> String
+    {
+        // A Wednesday
+        let now = Utc.with_ymd_and_hms(2023, 12, 20, 12, 0, 0).unwrap();
+        Countdown::new(target, Zone::Region(Tz::UTC), now)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_weekday()
+    {
+        assert_eq!(
+            countdown("6pm Friday"),
+            "Friday 22 December 2023, 18:00:00 UTC is in 2 days and 6 hours (2d 6h)\nDiscord: `<t:1703268000:R>` -> <t:1703268000:R>"
+        );
+        assert!(countdown("next wednesday at 11am")
+            .starts_with("Wednesday 27 December 2023, 11:00:00 UTC is in 6 days and 23 hours"));
+    }
+
+    #[test]
+    fn test_date()
+    {
+        assert!(countdown("Dec 25")
+            .starts_with("Monday 25 December 2023, 00:00:00 UTC is in 4 days and 12 hours"));
+        assert!(countdown("Dec 1").starts_with("Sunday 1 December 2024"));
+        assert!(countdown("1 Dec 2023").contains("was 2 weeks, 5 days and 12 hours ago"));
+    }
+
+    #[test]
+    fn test_zone()
+    {
+        assert!(countdown("6pm Friday EST").starts_with(
+            "Friday 22 December 2023, 18:00:00 EST [UTC-05:00] is in 2 days and 11 hours"
+        ));
+        assert!(countdown("9am New York").starts_with(
+            "Wednesday 20 December 2023, 09:00:00 EST [America/New_York] is in 2 hours"
+        ));
+        assert!(
+            countdown("1pm").starts_with("Wednesday 20 December 2023, 13:00:00 UTC is in 1 hour")
+        );
+        assert!(countdown("11am").starts_with("Thursday 21 December 2023"));
+        assert!(Countdown::new("6pm Narnia", Zone::Region(Tz::UTC), Utc::now()).is_err());
+    }
+}
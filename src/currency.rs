@@ -1,29 +1,50 @@
 use super::strip_suffixes;
+use crate::{
+    i18n::{aliases, Aliases, Locale},
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+};
 use chrono::{DateTime, Duration, Utc};
 use serde_derive::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, str::FromStr};
 use thiserror::Error;
 
 #[derive(Error, Clone, Debug)]
+#[error("{}", self.text(Locale::English))]
 pub enum CurrencyError
 {
-    #[error("NumberParseError: couldn't parse number from '{input}': {message}")]
-    Parse
-    {
-        input: String, message: String
-    },
-
-    #[error("RequestError: couldn't request data from currency API: {message}")]
+    /// A currency which isn't supported, or an amount without one
+    InvalidCurrency(String),
+    InvalidNumber(String),
     Request
     {
-        message: String
+        message: String,
     },
+    JsonParse,
+}
 
-    #[error("JSONParseError: couldn't parse JSON returned by currency API: {message}")]
-    JsonParse
+impl CurrencyError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
     {
-        message: String
-    },
+        match self
+        {
+            Self::InvalidCurrency(input) =>
+            {
+                locale.text("error.currency.invalid-currency", &[("input", input)])
+            }
+            Self::InvalidNumber(number) =>
+            {
+                locale.text("error.invalid-number", &[("number", number)])
+            }
+            Self::Request { message } =>
+            {
+                locale.text("error.currency.request", &[("message", message)])
+            }
+            Self::JsonParse => locale.text("error.currency.json", &[]),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -100,9 +121,7 @@ impl ExchangeRatesResponse
         }
         else
         {
-            Err(CurrencyError::JsonParse {
-                message: "Invalid JSON content".to_string(),
-            })
+            Err(CurrencyError::JsonParse)
         }
     }
 }
@@ -171,9 +190,29 @@ impl ExchangeRates
             pkr: resp.data.PKR.value,
         })
     }
+
+    /// The value of one U.S. Dollar in `currency`.
+    pub fn rate(&self, currency: CurrencyType) -> f64
+    {
+        match currency
+        {
+            CurrencyType::Usd => self.usd,
+            CurrencyType::Eur => self.eur,
+            CurrencyType::Cad => self.cad,
+            CurrencyType::Rub => self.rub,
+            CurrencyType::Jpy => self.jpy,
+            CurrencyType::Aud => self.aud,
+            CurrencyType::Amd => self.amd,
+            CurrencyType::Gbp => self.gbp,
+            CurrencyType::Pkr => self.pkr,
+        }
+    }
+
+    /// When the exchange rates were fetched.
+    pub fn when(&self) -> DateTime<Utc> { self.when }
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, Eq, Ord, Serialize, Deserialize)]
 pub enum CurrencyType
 {
     // Euro
@@ -204,24 +243,140 @@ pub enum CurrencyType
     Pkr,
 }
 
+impl CurrencyType
+{
+    pub const ALL: [Self; 9] = [
+        Self::Usd,
+        Self::Eur,
+        Self::Gbp,
+        Self::Cad,
+        Self::Aud,
+        Self::Jpy,
+        Self::Rub,
+        Self::Amd,
+        Self::Pkr,
+    ];
+
+    /// The ISO 4217 code (e.g. 'USD'), which parses back.
+    pub fn code(self) -> String { format!("{self:?}").to_uppercase() }
+
+    /// The currency's name in `locale`, with its code (e.g. 'Euro(s) [EUR]').
+    pub fn name(self, locale: Locale) -> String
+    {
+        locale.text(
+            &format!("currency.{}", format!("{self:?}").to_lowercase()),
+            &[],
+        )
+    }
+
+    /// What's converted to when no target is given: dollars, or euros for
+    /// amounts already in dollars.
+    pub fn counterpart(self) -> Self
+    {
+        match self
+        {
+            Self::Usd => Self::Eur,
+            _ => Self::Usd,
+        }
+    }
+}
+
 impl fmt::Display for CurrencyType
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let s = match self
-        {
-            Self::Usd => "Dollar(s) [USD]",
-            Self::Eur => "Euro(s) [EUR]",
-            Self::Cad => "Canadian Dollar(s) [CAD]",
-            Self::Rub => "Ruble(s) [RUB]",
-            Self::Jpy => "Yen [JPY]",
-            Self::Aud => "Austriallian Dollar(s) [AUD]",
-            Self::Amd => "Dram [AMD]",
-            Self::Gbp => "Brittish Pound(s) [GBP]",
-            Self::Pkr => "Pakistani rupee(s) [PKR]",
-        };
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+/// Currency names in other languages, with the plural forms amounts are
+/// written with (e.g. Russian '1 рубль', '2 рубля', '5 рублей').
+const LOCALISED_NAMES: &[Aliases<CurrencyType>] = &[
+    (
+        "de",
+        &[("pfund", CurrencyType::Gbp), ("rubel", CurrencyType::Rub)],
+    ),
+    (
+        "es",
+        &[
+            ("dólar", CurrencyType::Usd),
+            ("dólares", CurrencyType::Usd),
+            ("euros", CurrencyType::Eur),
+            ("libra", CurrencyType::Gbp),
+            ("libras", CurrencyType::Gbp),
+            ("rublo", CurrencyType::Rub),
+            ("rublos", CurrencyType::Rub),
+            ("yenes", CurrencyType::Jpy),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            ("доллар", CurrencyType::Usd),
+            ("доллара", CurrencyType::Usd),
+            ("долларов", CurrencyType::Usd),
+            ("евро", CurrencyType::Eur),
+            ("фунт", CurrencyType::Gbp),
+            ("фунта", CurrencyType::Gbp),
+            ("фунтов", CurrencyType::Gbp),
+            ("руб", CurrencyType::Rub),
+            ("рубль", CurrencyType::Rub),
+            ("рубля", CurrencyType::Rub),
+            ("рублей", CurrencyType::Rub),
+            ("₽", CurrencyType::Rub),
+            ("иена", CurrencyType::Jpy),
+            ("иены", CurrencyType::Jpy),
+            ("иен", CurrencyType::Jpy),
+            ("драм", CurrencyType::Amd),
+            ("драма", CurrencyType::Amd),
+            ("драмов", CurrencyType::Amd),
+        ],
+    ),
+    (
+        "hy",
+        &[("դրամ", CurrencyType::Amd), ("֏", CurrencyType::Amd)],
+    ),
+    (
+        "ja",
+        &[
+            ("円", CurrencyType::Jpy),
+            ("ドル", CurrencyType::Usd),
+            ("ユーロ", CurrencyType::Eur),
+        ],
+    ),
+];
+
+/// The longest currency name in another language that `s` ends with.
+fn localised_suffix(s: &str) -> Option<(&'static str, CurrencyType)>
+{
+    aliases(LOCALISED_NAMES)
+        .filter(|(name, _)| s.ends_with(name))
+        .max_by_key(|(name, _)| name.len())
+}
 
-        write!(f, "{s}")
+impl FromStr for CurrencyType
+{
+    type Err = CurrencyError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "$" | "usd" | "dollar" => Self::Usd,
+            "€" | "eur" | "euro" => Self::Eur,
+            "cad" => Self::Cad,
+            "rub" | "ruble" => Self::Rub,
+            "¥" | "yen" | "jpy" => Self::Jpy,
+            "aud" => Self::Aud,
+            "amd" | "dram" => Self::Amd,
+            "£" | "gbp" | "pound" | "sterling" | "quid" => Self::Gbp,
+            "pakistani rupee" | "pkr" => Self::Pkr,
+            name => match aliases(LOCALISED_NAMES).find(|(alias, _)| *alias == name)
+            {
+                Some((_, currency)) => currency,
+                None => return Err(CurrencyError::InvalidCurrency(s.trim().to_string())),
+            },
+        })
     }
 }
 
@@ -240,13 +395,56 @@ impl Currency
 {
     pub fn into_currency(&mut self, currency: CurrencyType) { self.currency = currency; }
 
+    /// The amount as text in `locale` (e.g. '74.56 Euro(s) [EUR]').
+    pub fn text(&self, locale: Locale) -> String
+    {
+        // Store all currencies as USD
+        let value = self.converter.exchange_rates.rate(self.currency) * self.value;
+        format!("{value:.2} {}", self.currency.name(locale))
+    }
+
     pub fn get_converter(&self) -> CurrencyConverter { self.converter.clone() }
 
+    /// An amount of `currency`.
+    pub fn new(
+        value: f64,
+        currency: CurrencyType,
+        converter: CurrencyConverter,
+    ) -> Result<Self, CurrencyError>
+    {
+        // Store all currencies as USD
+        let converter = Self::refresh_exchange_rates(converter)?;
+        Ok(Currency {
+            value: value / converter.exchange_rates.rate(currency),
+            currency,
+            converter,
+        })
+    }
+
     pub fn from_str(s: &str, converter: CurrencyConverter) -> Result<Self, CurrencyError>
+    {
+        let (value, currency) = Self::parse_amount(s)?;
+        Self::new(value, currency, converter)
+    }
+
+    /// Find an amount written in running text (e.g. '$74', '20 quid'), without
+    /// converting it, so the exchange rates aren't needed.
+    pub fn scan(s: &str) -> Option<(f64, CurrencyType)>
+    {
+        if !s.chars().any(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+        Self::parse_amount(s).ok()
+    }
+
+    /// Split an amount like '$74' or '80.90 CAD' into its value and currency,
+    /// without converting it.
+    fn parse_amount(s: &str) -> Result<(f64, CurrencyType), CurrencyError>
     {
         let mut s = s.to_lowercase();
         let currency;
-        let mut value;
+
         match s
         {
             _ if s.ends_with("usd") || s.ends_with("dollar") || s.starts_with('$') =>
@@ -324,53 +522,28 @@ impl Currency
                 s = strip_suffixes(s, &["pkr", "pakistani rupee"]);
                 currency = CurrencyType::Pkr;
             }
-            _ =>
+            _ => match localised_suffix(&s)
             {
-                return Err(CurrencyError::Parse {
-                    input: s,
-                    message: "Invalid unit provided.".to_string(),
-                })
-            }
+                Some((name, x)) =>
+                {
+                    s.truncate(s.len() - name.len());
+                    currency = x;
+                }
+                None => return Err(CurrencyError::InvalidCurrency(s.trim().to_string())),
+            },
         };
 
-        value = match s.trim().parse()
+        let value = match s.trim().parse()
         {
-            Err(e) =>
-            {
-                return Err(CurrencyError::Parse {
-                    input: s,
-                    message: format!("{e}"),
-                })
-            }
+            Err(_) => return Err(CurrencyError::InvalidNumber(s.trim().to_string())),
             Ok(v) => v,
         };
 
-        // Store all currencies as USD
-        let converter = Self::refresh_exchange_rates(converter)?;
-
-        let exchange_rates = converter.exchange_rates;
-        value /= match currency
-        {
-            CurrencyType::Usd => exchange_rates.usd,
-            CurrencyType::Eur => exchange_rates.eur,
-            CurrencyType::Cad => exchange_rates.cad,
-            CurrencyType::Rub => exchange_rates.rub,
-            CurrencyType::Jpy => exchange_rates.jpy,
-            CurrencyType::Aud => exchange_rates.aud,
-            CurrencyType::Amd => exchange_rates.amd,
-            CurrencyType::Gbp => exchange_rates.gbp,
-            CurrencyType::Pkr => exchange_rates.pkr,
-        };
-
-        Ok(Currency {
-            value,
-            currency,
-            converter,
-        })
+        Ok((value, currency))
     }
 
     /// If the exchange rates are too old, refresh them.
-    fn refresh_exchange_rates(
+    pub(crate) fn refresh_exchange_rates(
         mut converter: CurrencyConverter,
     ) -> Result<CurrencyConverter, CurrencyError>
     {
@@ -393,22 +566,7 @@ impl fmt::Display for Currency
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        // Store all currencies as USD
-        let exchange_rates = self.converter.exchange_rates;
-        let value = match self.currency
-        {
-            CurrencyType::Usd => exchange_rates.usd,
-            CurrencyType::Eur => exchange_rates.eur,
-            CurrencyType::Cad => exchange_rates.cad,
-            CurrencyType::Rub => exchange_rates.rub,
-            CurrencyType::Jpy => exchange_rates.jpy,
-            CurrencyType::Aud => exchange_rates.aud,
-            CurrencyType::Amd => exchange_rates.amd,
-            CurrencyType::Gbp => exchange_rates.gbp,
-            CurrencyType::Pkr => exchange_rates.pkr,
-        } * self.value;
-
-        write!(f, "{value:.2} {}", self.currency)
+        write!(f, "{}", self.text(Locale::English))
     }
 }
 
@@ -435,44 +593,152 @@ impl CurrencyConverter
             max_age,
         })
     }
+
+    pub fn exchange_rates(&self) -> ExchangeRates { self.exchange_rates }
 }
 
+/// The `currency` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new("currency", "Convert from one currency to another.")
+.options(&[
+    OptionSpec::string("input", "The input currency (e.g. '$74', '80.90 CAD', '20 quid')."),
+    OptionSpec::string("target", "The currency to convert to: USD, EUR, CAD, RUB, JPY, AUD, AMD, GBP, or PKR.")
+        .optional()
+        .details("The currency to convert to (e.g 'rubles', 'usd', 'yen'). Supported currencies: USD, EUR, CAD, RUB, JPY, AUD, AMD, GBP, and PKR. Defaults to your preferred currency, or USD"),
+]);
+
+/// The `currency` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "currency",
+    "Convert an amount from one currency to another.",
+)
+.options(&[
+    OptionSpec::number("value", "The amount to convert (e.g. 74)."),
+    OptionSpec::string("unit", "The currency the amount is in.").choices(choices),
+    OptionSpec::string(
+        "target",
+        "The currency to convert to. Defaults to your preferred currency.",
+    )
+    .optional()
+    .choices(choices),
+]);
+
 pub fn run(
     converter: CurrencyConverter,
     input: String,
     target: String,
 ) -> (String, CurrencyConverter)
 {
-    let mut value = match Currency::from_str(&input, converter.clone())
+    let (reply, converter) = convert(converter, input, Some(target), Locale::English);
+    (reply.to_string(), converter)
+}
+
+/// Like [`run`], but keeps the result's structure, including the exchange
+/// rate used, written in `locale`. Without a target, amounts are converted to
+/// dollars, or to euros if they're already in dollars.
+pub fn convert(
+    converter: CurrencyConverter,
+    input: String,
+    target: Option<String>,
+    locale: Locale,
+) -> (Reply, CurrencyConverter)
+{
+    match Currency::from_str(&input, converter.clone())
     {
-        Ok(x) => x,
-        Err(e) => return (e.to_string(), converter),
-    };
+        Ok(value) => convert_currency(value, target, converter, locale),
+        Err(e) => (Reply::Error(e.text(locale)), converter),
+    }
+}
 
-    let initial_value = value.to_string();
+/// Like [`convert`], but with the amount and its currency given separately
+/// (e.g. from typed slash command options).
+pub fn convert_value(
+    converter: CurrencyConverter,
+    value: f64,
+    currency: String,
+    target: Option<String>,
+    locale: Locale,
+) -> (Reply, CurrencyConverter)
+{
+    let value = CurrencyType::from_str(&currency)
+        .and_then(|currency| Currency::new(value, currency, converter.clone()));
+    match value
+    {
+        Ok(value) => convert_currency(value, target, converter, locale),
+        Err(e) => (Reply::Error(e.text(locale)), converter),
+    }
+}
 
-    value.into_currency(match &*target.trim().to_lowercase()
+/// Convert `value` into `target`, or its currency's counterpart.
+pub(crate) fn convert_currency(
+    mut value: Currency,
+    target: Option<String>,
+    converter: CurrencyConverter,
+    locale: Locale,
+) -> (Reply, CurrencyConverter)
+{
+    let initial_value = value.text(locale);
+    let from = value.currency;
+    let target = match target.map(|x| CurrencyType::from_str(&x)).transpose()
     {
-        "$" | "usd" | "dollar" => CurrencyType::Usd,
-        "€" | "eur" | "euro" => CurrencyType::Eur,
-        "cad" => CurrencyType::Cad,
-        "rub" | "ruble" => CurrencyType::Rub,
-        "¥" | "yen" | "jpy" => CurrencyType::Jpy,
-        "aud" => CurrencyType::Aud,
-        "amd" | "dram" => CurrencyType::Amd,
-        "pound" | "sterling" | "quid" => CurrencyType::Gbp,
-        "pakistani rupee" | "pkr" => CurrencyType::Pkr,
-        _ => return ("Error: Invalid target currency".to_string(), converter),
-    });
-
-    (format!("{initial_value} -> {value}"), value.get_converter())
+        Ok(x) => x.unwrap_or_else(|| from.counterpart()),
+        Err(_) =>
+        {
+            return (
+                Reply::Error(locale.text("error.currency.invalid-target", &[])),
+                converter,
+            )
+        }
+    };
+    value.into_currency(target);
+
+    let rates = value.converter.exchange_rates;
+    let conversion = Conversion {
+        input: initial_value,
+        output: value.text(locale),
+        units: Some((from.name(locale), target.name(locale))),
+        rate: Some(rates.rate(target) / rates.rate(from)),
+        rate_time: Some(rates.when),
+        source: Some("currencyapi.com"),
+        ..Default::default()
+    };
+    (Reply::Conversion(conversion), value.get_converter())
+}
+
+/// Each currency as a slash command choice.
+pub fn choices() -> Vec<(String, String)>
+{
+    CurrencyType::ALL
+        .iter()
+        .map(|currency| (currency.to_string(), format!("{currency:?}").to_lowercase()))
+        .collect()
 }
 
 #[cfg(test)]
-mod tests
+pub(crate) mod tests
 {
     use super::*;
 
+    /// A converter with fixed exchange rates, for tests elsewhere in the crate.
+    pub(crate) fn converter() -> CurrencyConverter
+    {
+        CurrencyConverter {
+            exchange_rates: ExchangeRates {
+                when: Utc::now(),
+                eur: 0.932001,
+                usd: 1.0,
+                cad: 1.344352,
+                rub: 71.510096,
+                jpy: 132.626755,
+                aud: 1.451866,
+                amd: 396.62057,
+                gbp: 0.831541,
+                pkr: 281.850466,
+            },
+            api_key: "NONE".to_string(),
+            max_age: Duration::hours(24),
+        }
+    }
+
     #[test]
     fn test_currency_to_string_usd()
     {
@@ -706,4 +972,63 @@ mod tests
             "45.00 Dollar(s) [USD] -> 12683.27 Pakistani rupee(s) [PKR]".to_string()
         )
     }
+
+    #[test]
+    fn test_typed_options()
+    {
+        let converter = converter();
+        for (name, value) in choices()
+        {
+            assert_eq!(CurrencyType::from_str(&value).unwrap().to_string(), name);
+        }
+
+        assert_eq!(
+            convert_value(
+                converter.clone(),
+                45.9,
+                "usd".to_string(),
+                Some("dram".to_string()),
+                Locale::English
+            )
+            .0
+            .to_string(),
+            run(converter.clone(), "$45.9".to_string(), "dram".to_string()).0
+        );
+        assert_eq!(
+            convert(converter, "€10".to_string(), None, Locale::English)
+                .0
+                .to_string(),
+            "10.00 Euro(s) [EUR] -> 10.73 Dollar(s) [USD]"
+        );
+    }
+
+    #[test]
+    fn test_localised_names()
+    {
+        for (input, currency) in [
+            ("1 рубль", CurrencyType::Rub),
+            ("2 рубля", CurrencyType::Rub),
+            ("100 рублей", CurrencyType::Rub),
+            ("50 евро", CurrencyType::Eur),
+            ("1000円", CurrencyType::Jpy),
+            ("500 драм", CurrencyType::Amd),
+            ("20 libras", CurrencyType::Gbp),
+            ("5 Pfund", CurrencyType::Gbp),
+        ]
+        {
+            assert_eq!(
+                Currency::scan(input).map(|x| x.1),
+                Some(currency),
+                "{input}"
+            );
+        }
+        assert!(matches!(
+            CurrencyType::from_str("Рублей"),
+            Ok(CurrencyType::Rub)
+        ));
+        assert_eq!(
+            run(converter(), "7151 рублей".to_string(), "usd".to_string()).0,
+            "7151.00 Ruble(s) [RUB] -> 100.00 Dollar(s) [USD]"
+        );
+    }
 }
@@ -0,0 +1,741 @@
+use crate::{
+    i18n::Locale,
+    quantity::{self, ParseUnitError},
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+    time::format_duration,
+};
+use chrono::Duration;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `data` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "data",
+    "Convert between data sizes or rates. Supports SI (kB, MB) and IEC (KiB, MiB) units.",
+)
+.options(&[
+    OptionSpec::string("value", "Original value (e.g. '50 GiB', '100 Mbps', '12.5 MB/s')."),
+    OptionSpec::string("target", "The unit to target. (e.g 'GB', 'MiB', 'MB/s').")
+        .details("The unit to target. (e.g 'GB', 'MiB', 'MB/s'). Lowercase 'b' is bits, uppercase 'B' is bytes"),
+]);
+
+/// The `transfer` subcommand.
+pub const TRANSFER_SUBCOMMAND: SubcommandSpec =
+    SubcommandSpec::new("transfer", "Estimate how long a transfer takes.").options(&[
+        OptionSpec::string("size", "The amount of data (e.g. '50 GiB', '700 MB')."),
+        OptionSpec::string("rate", "The transfer rate (e.g. '100 Mbps', '2 MB/s')."),
+    ]);
+
+/// The `data` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "data",
+    "Convert a number from one data size or rate unit to another.",
+)
+.options(&[
+    OptionSpec::number("value", "The number to convert (e.g. 50)."),
+    OptionSpec::string("unit", "The unit the number is in (e.g. 'GiB', 'Mbps').").choices(choices),
+    OptionSpec::string("target", "The unit to convert to (e.g. 'GB', 'MB/s').").choices(choices),
+]);
+
+pub fn run(input: String, target: String) -> String
+{
+    convert(input, target, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+pub fn convert(input: String, target: String, locale: Locale) -> Reply
+{
+    if let Ok(rate) = DataRate::from_str(&input)
+    {
+        return convert_rate(rate, &target, locale);
+    }
+    match DataSize::from_str(&input)
+    {
+        Ok(size) => convert_size(size, &target, locale),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+/// Like [`convert`], but with the number and its unit given separately (e.g.
+/// from typed slash command options).
+pub fn convert_value(value: f64, unit: String, target: String, locale: Locale) -> Reply
+{
+    if let Ok(unit) = DataRate::parse_unit(&unit)
+    {
+        return convert_rate(DataRate::new(value, unit), &target, locale);
+    }
+    match DataUnit::from_str(&unit)
+    {
+        Ok(unit) => convert_size(DataSize::new(value, unit), &target, locale),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+fn convert_rate(mut rate: DataRate, target: &str, locale: Locale) -> Reply
+{
+    let original = rate;
+    match DataRate::parse_unit(target)
+    {
+        Ok(unit) => rate.into_unit(unit),
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    let per_second =
+        |unit: DataUnit| locale.text("data.per-second", &[("unit", &unit.name(locale))]);
+    Reply::Conversion(Conversion {
+        input: original.text(locale),
+        output: rate.text(locale),
+        units: Some((per_second(original.unit), per_second(rate.unit))),
+        notes: notes(&[original.unit, rate.unit], locale),
+        ..Default::default()
+    })
+}
+
+fn convert_size(mut size: DataSize, target: &str, locale: Locale) -> Reply
+{
+    let original = size;
+    match DataUnit::from_str(target)
+    {
+        Ok(unit) => size.into_unit(unit),
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    Reply::Conversion(Conversion {
+        input: original.text(locale),
+        output: size.text(locale),
+        units: Some((original.unit.name(locale), size.unit.name(locale))),
+        notes: notes(&[original.unit, size.unit], locale),
+        ..Default::default()
+    })
+}
+
+/// Every size and rate unit as a slash command choice. There are too many to
+/// list, so they're autocompleted.
+pub fn choices() -> Vec<(String, String)>
+{
+    let units: Vec<DataUnit> = DataPrefix::ALL
+        .iter()
+        .flat_map(|&prefix| [DataBase::Byte, DataBase::Bit].map(|base| DataUnit::new(prefix, base)))
+        .collect();
+    let sizes = units.iter().map(|unit| (unit.to_string(), unit.symbol()));
+    let rates = units
+        .iter()
+        .map(|unit| (format!("{unit} per second"), format!("{}/s", unit.symbol())));
+    sizes.chain(rates).collect()
+}
+
+/// Estimate how long it takes to transfer `size` at `rate`.
+pub fn run_transfer(size: String, rate: String) -> String
+{
+    convert_transfer(size, rate, Locale::English).to_string()
+}
+
+/// Like [`run_transfer`], but keeps the result's structure, written in
+/// `locale`.
+pub fn convert_transfer(size: String, rate: String, locale: Locale) -> Reply
+{
+    let size = match DataSize::from_str(&size)
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    let rate = match DataRate::from_str(&rate)
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    match size.transfer_time(&rate)
+    {
+        Ok(duration) => Reply::Conversion(Conversion {
+            input: locale.text(
+                "data.transfer",
+                &[("size", &size.text(locale)), ("rate", &rate.text(locale))],
+            ),
+            output: format_duration(duration),
+            notes: notes(&[size.unit, rate.unit], locale),
+            ..Default::default()
+        }),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+/// Explain what each prefixed unit used actually means, so SI and IEC units
+/// can't be mixed up.
+fn notes(units: &[DataUnit], locale: Locale) -> Vec<String>
+{
+    let mut notes = Vec::new();
+    let mut seen = Vec::new();
+    for unit in units
+    {
+        if unit.prefix != DataPrefix::None && !seen.contains(unit)
+        {
+            seen.push(*unit);
+            notes.push(unit.describe(locale));
+        }
+    }
+    notes
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum ParseDataError
+{
+    InvalidUnit(String),
+    InvalidNumber(String),
+    UnexpectedRate(String),
+    ExpectedRate(String),
+    NotPositiveRate,
+    TooLong(DataSize),
+}
+
+impl ParseDataError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::InvalidUnit(unit) => locale.text("error.invalid-unit", &[("unit", unit)]),
+            Self::InvalidNumber(number) =>
+            {
+                locale.text("error.invalid-number", &[("number", number)])
+            }
+            Self::UnexpectedRate(unit) =>
+            {
+                locale.text("error.data.unexpected-rate", &[("unit", unit)])
+            }
+            Self::ExpectedRate(unit) => locale.text("error.data.expected-rate", &[("unit", unit)]),
+            Self::NotPositiveRate => locale.text("error.data.not-positive-rate", &[]),
+            Self::TooLong(size) =>
+            {
+                locale.text("error.data.too-long", &[("size", &size.text(locale))])
+            }
+        }
+    }
+}
+
+impl From<ParseUnitError> for ParseDataError
+{
+    fn from(e: ParseUnitError) -> Self
+    {
+        match e
+        {
+            ParseUnitError::InvalidUnit(unit) => Self::InvalidUnit(unit),
+            ParseUnitError::InvalidNumber(number) => Self::InvalidNumber(number),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataPrefix
+{
+    None,
+
+    // SI (decimal) prefixes
+    Kilo,
+    Mega,
+    Giga,
+    Tera,
+    Peta,
+
+    // IEC (binary) prefixes
+    Kibi,
+    Mebi,
+    Gibi,
+    Tebi,
+    Pebi,
+}
+
+impl DataPrefix
+{
+    pub const ALL: [Self; 11] = [
+        Self::None,
+        Self::Kilo,
+        Self::Mega,
+        Self::Giga,
+        Self::Tera,
+        Self::Peta,
+        Self::Kibi,
+        Self::Mebi,
+        Self::Gibi,
+        Self::Tebi,
+        Self::Pebi,
+    ];
+
+    /// The number of base units in one prefixed unit.
+    pub fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::None => 1.0,
+            Self::Kilo => 1e3,
+            Self::Mega => 1e6,
+            Self::Giga => 1e9,
+            Self::Tera => 1e12,
+            Self::Peta => 1e15,
+            Self::Kibi => 1024.0,
+            Self::Mebi => 1024.0_f64.powi(2),
+            Self::Gibi => 1024.0_f64.powi(3),
+            Self::Tebi => 1024.0_f64.powi(4),
+            Self::Pebi => 1024.0_f64.powi(5),
+        }
+    }
+
+    /// Whether this is an IEC (power of 1024) prefix.
+    pub fn is_binary(&self) -> bool
+    {
+        matches!(
+            self,
+            Self::Kibi | Self::Mebi | Self::Gibi | Self::Tebi | Self::Pebi
+        )
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "",
+            Self::Kilo => "k",
+            Self::Mega => "M",
+            Self::Giga => "G",
+            Self::Tera => "T",
+            Self::Peta => "P",
+            Self::Kibi => "Ki",
+            Self::Mebi => "Mi",
+            Self::Gibi => "Gi",
+            Self::Tebi => "Ti",
+            Self::Pebi => "Pi",
+        }
+    }
+
+    fn name(&self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "",
+            Self::Kilo => "Kilo",
+            Self::Mega => "Mega",
+            Self::Giga => "Giga",
+            Self::Tera => "Tera",
+            Self::Peta => "Peta",
+            Self::Kibi => "Kibi",
+            Self::Mebi => "Mebi",
+            Self::Gibi => "Gibi",
+            Self::Tebi => "Tebi",
+            Self::Pebi => "Pebi",
+        }
+    }
+
+    /// How the prefix is written as a power (e.g. '1024^3' or '1000^3').
+    fn power(&self) -> &'static str
+    {
+        match self
+        {
+            Self::None => "1",
+            Self::Kilo => "1000",
+            Self::Mega => "1000^2",
+            Self::Giga => "1000^3",
+            Self::Tera => "1000^4",
+            Self::Peta => "1000^5",
+            Self::Kibi => "1024",
+            Self::Mebi => "1024^2",
+            Self::Gibi => "1024^3",
+            Self::Tebi => "1024^4",
+            Self::Pebi => "1024^5",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataBase
+{
+    Bit,
+    Byte,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DataUnit
+{
+    pub prefix: DataPrefix,
+    pub base: DataBase,
+}
+
+impl DataUnit
+{
+    pub fn new(prefix: DataPrefix, base: DataBase) -> Self { Self { prefix, base } }
+
+    /// The number of bits in one of this unit.
+    pub fn bits(&self) -> f64
+    {
+        self.prefix.factor()
+            * match self.base
+            {
+                DataBase::Bit => 1.0,
+                DataBase::Byte => 8.0,
+            }
+    }
+
+    /// The short form of the unit (e.g. 'GiB', 'Mb').
+    pub fn symbol(&self) -> String
+    {
+        let base = match self.base
+        {
+            DataBase::Bit => "b",
+            DataBase::Byte => "B",
+        };
+        format!("{}{base}", self.prefix.symbol())
+    }
+
+    /// A sentence explaining the size of the unit in `locale` (e.g. '1 GiB =
+    /// 1024^3 bytes (IEC, binary)').
+    pub fn describe(&self, locale: Locale) -> String
+    {
+        let key = match (self.base, self.prefix.is_binary())
+        {
+            (DataBase::Bit, true) => "data.bits-iec",
+            (DataBase::Bit, false) => "data.bits-si",
+            (DataBase::Byte, true) => "data.bytes-iec",
+            (DataBase::Byte, false) => "data.bytes-si",
+        };
+        locale.text(
+            key,
+            &[("symbol", &self.symbol()), ("power", self.prefix.power())],
+        )
+    }
+
+    /// The unit's name in `locale`, with its symbol (e.g. 'Gibibyte(s) [GiB,
+    /// IEC]').
+    pub fn name(&self, locale: Locale) -> String
+    {
+        let base = match (self.prefix, self.base)
+        {
+            (DataPrefix::None, DataBase::Bit) => "Bit",
+            (DataPrefix::None, DataBase::Byte) => "Byte",
+            (_, DataBase::Bit) => "bit",
+            (_, DataBase::Byte) => "byte",
+        };
+        let standard = match self.prefix
+        {
+            DataPrefix::None => "",
+            _ if self.prefix.is_binary() => ", IEC",
+            _ => ", SI",
+        };
+        locale.text(
+            "data.unit",
+            &[
+                ("name", &format!("{}{base}", self.prefix.name())),
+                ("symbol", &format!("{}{standard}", self.symbol())),
+            ],
+        )
+    }
+}
+
+impl FromStr for DataUnit
+{
+    type Err = ParseDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim();
+        let invalid = || ParseDataError::InvalidUnit(s.to_string());
+        if s.is_empty()
+        {
+            return Err(invalid());
+        }
+        if s.ends_with("/s") || s.ends_with("ps")
+        {
+            return Err(ParseDataError::UnexpectedRate(s.to_string()));
+        }
+
+        // Spelled out units (e.g. 'gibibytes', 'megabit')
+        let word = s.to_lowercase();
+        let word = word.strip_suffix('s').unwrap_or(&word);
+        let base = if word.ends_with("byte")
+        {
+            Some(DataBase::Byte)
+        }
+        else if word.ends_with("bit")
+        {
+            Some(DataBase::Bit)
+        }
+        else
+        {
+            None
+        };
+        if let Some(base) = base
+        {
+            let prefix = match &word[..word.len() - if base == DataBase::Byte { 4 } else { 3 }]
+            {
+                "" => DataPrefix::None,
+                "kilo" => DataPrefix::Kilo,
+                "mega" => DataPrefix::Mega,
+                "giga" => DataPrefix::Giga,
+                "tera" => DataPrefix::Tera,
+                "peta" => DataPrefix::Peta,
+                "kibi" => DataPrefix::Kibi,
+                "mebi" => DataPrefix::Mebi,
+                "gibi" => DataPrefix::Gibi,
+                "tebi" => DataPrefix::Tebi,
+                "pebi" => DataPrefix::Pebi,
+                _ => return Err(invalid()),
+            };
+            return Ok(Self::new(prefix, base));
+        }
+
+        // Symbols, where 'b' is a bit and 'B' is a byte (e.g. 'GiB', 'Mb')
+        let base = match s.chars().last()
+        {
+            Some('b') => DataBase::Bit,
+            Some('B') => DataBase::Byte,
+            _ => return Err(invalid()),
+        };
+        let prefix = match &*s[..s.len() - 1].to_lowercase()
+        {
+            "" => DataPrefix::None,
+            "k" => DataPrefix::Kilo,
+            "m" => DataPrefix::Mega,
+            "g" => DataPrefix::Giga,
+            "t" => DataPrefix::Tera,
+            "p" => DataPrefix::Peta,
+            "ki" => DataPrefix::Kibi,
+            "mi" => DataPrefix::Mebi,
+            "gi" => DataPrefix::Gibi,
+            "ti" => DataPrefix::Tebi,
+            "pi" => DataPrefix::Pebi,
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self::new(prefix, base))
+    }
+}
+
+impl fmt::Display for DataUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// An amount of data, stored in bits.
+pub struct DataSize
+{
+    bits: f64,
+    unit: DataUnit,
+}
+
+impl DataSize
+{
+    pub fn new(value: f64, unit: DataUnit) -> Self
+    {
+        Self {
+            bits: value * unit.bits(),
+            unit,
+        }
+    }
+
+    pub fn into_unit(&mut self, unit: DataUnit) { self.unit = unit; }
+
+    /// The size in its current unit.
+    pub fn value(&self) -> f64 { self.bits / self.unit.bits() }
+
+    /// The size as text in `locale` (e.g. '50.00 Gibibyte(s) [GiB, IEC]').
+    pub fn text(&self, locale: Locale) -> String
+    {
+        format!("{:.2} {}", self.value(), self.unit.name(locale))
+    }
+
+    /// How long it takes to transfer this amount of data at `rate`. Rates
+    /// which aren't positive, and transfers longer than durations can be (about
+    /// 30 million years), are rejected.
+    pub fn transfer_time(&self, rate: &DataRate) -> Result<Duration, ParseDataError>
+    {
+        if rate.bits_per_second <= 0.0
+        {
+            return Err(ParseDataError::NotPositiveRate);
+        }
+        let seconds = self.bits / rate.bits_per_second;
+        if !seconds.is_finite() || seconds > 1e15
+        {
+            return Err(ParseDataError::TooLong(*self));
+        }
+        Ok(Duration::milliseconds((seconds * 1000.0).round() as i64))
+    }
+}
+
+impl FromStr for DataSize
+{
+    type Err = ParseDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (value, unit) = quantity::split_quantity(s)?;
+        Ok(Self::new(value, unit.parse()?))
+    }
+}
+
+impl fmt::Display for DataSize
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A data transfer rate, stored in bits per second.
+pub struct DataRate
+{
+    bits_per_second: f64,
+    unit: DataUnit,
+}
+
+impl DataRate
+{
+    pub fn new(value: f64, unit: DataUnit) -> Self
+    {
+        Self {
+            bits_per_second: value * unit.bits(),
+            unit,
+        }
+    }
+
+    pub fn into_unit(&mut self, unit: DataUnit) { self.unit = unit; }
+
+    /// The rate in its current unit per second.
+    pub fn value(&self) -> f64 { self.bits_per_second / self.unit.bits() }
+
+    /// The rate as text in `locale` (e.g. '12.50 Megabyte(s) [MB, SI] per
+    /// second').
+    pub fn text(&self, locale: Locale) -> String
+    {
+        let unit = self.unit.name(locale);
+        format!(
+            "{:.2} {}",
+            self.value(),
+            locale.text("data.per-second", &[("unit", &unit)])
+        )
+    }
+
+    /// Parse a per-second unit (e.g. 'Mbps', 'MB/s', 'MiB/s').
+    pub fn parse_unit(s: &str) -> Result<DataUnit, ParseDataError>
+    {
+        let s = s.trim();
+        match s.strip_suffix("/s").or_else(|| s.strip_suffix("ps"))
+        {
+            Some(unit) => unit.parse(),
+            None => Err(ParseDataError::ExpectedRate(s.to_string())),
+        }
+    }
+}
+
+impl FromStr for DataRate
+{
+    type Err = ParseDataError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (value, unit) = quantity::split_quantity(s)?;
+        Ok(Self::new(value, Self::parse_unit(unit)?))
+    }
+}
+
+impl fmt::Display for DataRate
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_parse_units()
+    {
+        assert_eq!(
+            DataUnit::from_str("GiB").unwrap(),
+            DataUnit::new(DataPrefix::Gibi, DataBase::Byte)
+        );
+        assert_eq!(
+            DataUnit::from_str("kb").unwrap(),
+            DataUnit::new(DataPrefix::Kilo, DataBase::Bit)
+        );
+        assert_eq!(
+            DataUnit::from_str("Megabytes").unwrap(),
+            DataUnit::new(DataPrefix::Mega, DataBase::Byte)
+        );
+        assert_eq!(
+            DataRate::parse_unit("Mbps").unwrap(),
+            DataUnit::new(DataPrefix::Mega, DataBase::Bit)
+        );
+        assert_eq!(
+            DataRate::parse_unit("MiB/s").unwrap(),
+            DataUnit::new(DataPrefix::Mebi, DataBase::Byte)
+        );
+        assert!(DataUnit::from_str("Mbps").is_err());
+        assert!(DataRate::parse_unit("MB").is_err());
+    }
+
+    #[test]
+    fn test_si_vs_iec()
+    {
+        assert_eq!(
+            run("1 GiB".to_string(), "GB".to_string()),
+            "1.00 Gibibyte(s) [GiB, IEC] -> 1.07 Gigabyte(s) [GB, SI]\n1 GiB = 1024^3 bytes (IEC, \
+             binary)\n1 GB = 1000^3 bytes (SI, decimal)"
+        );
+    }
+
+    #[test]
+    fn test_rate_conversion()
+    {
+        let mut rate = DataRate::from_str("100 Mbps").unwrap();
+        rate.into_unit(DataRate::parse_unit("MB/s").unwrap());
+        assert_eq!(rate.to_string(), "12.50 Megabyte(s) [MB, SI] per second");
+    }
+
+    #[test]
+    fn test_transfer_time()
+    {
+        assert_eq!(
+            run_transfer("50 GiB".to_string(), "100 Mbps".to_string()),
+            "50.00 Gibibyte(s) [GiB, IEC] at 100.00 Megabit(s) [Mb, SI] per second -> 1h 11m \
+             35s\n1 GiB = 1024^3 bytes (IEC, binary)\n1 Mb = 1000^2 bits (SI, decimal)"
+        );
+        assert!(run_transfer("1 GB".to_string(), "0 Mbps".to_string()).starts_with("Error"));
+        assert!(run_transfer("10 PB".to_string(), "1 bps".to_string()).starts_with("Error"));
+        assert!(run_transfer("1e3 MB".to_string(), "1 MB/s".to_string()).contains("-> 16m 40s"));
+    }
+
+    #[test]
+    fn test_typed_options()
+    {
+        for (_, value) in choices()
+        {
+            assert!(
+                DataUnit::from_str(&value).is_ok() || DataRate::parse_unit(&value).is_ok(),
+                "'{value}' doesn't parse"
+            );
+        }
+
+        assert_eq!(
+            convert_value(
+                100.0,
+                "Mbps".to_string(),
+                "MB/s".to_string(),
+                Locale::English
+            )
+            .to_string(),
+            run("100 Mbps".to_string(), "MB/s".to_string())
+        );
+        assert_eq!(
+            convert_value(50.0, "GiB".to_string(), "GB".to_string(), Locale::English).to_string(),
+            run("50 GiB".to_string(), "GB".to_string())
+        );
+    }
+}
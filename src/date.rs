@@ -0,0 +1,673 @@
+use crate::{
+    i18n::Locale,
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+    time::{ParseTimeError, Time},
+};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `date` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "date",
+    "Convert a date between formats, and find its day of the week.",
+)
+.details("Convert a date between formats, and find its day of the week, ISO week, and day of the year")
+.options(&[
+    OptionSpec::string("value", "The date (e.g. '2023-07-15', '15/07/2023', 'July 15th 2023', '1689379200').")
+        .details("The date, optionally with a time (e.g. '2023-07-15', '15/07/2023 3pm', 'July 15th 2023', 'Sat, 15 Jul 2023 14:30:00 +0000', '1689379200')"),
+    OptionSpec::string("target", "The format to target ('iso', 'rfc2822', 'us', 'eu', 'unix', 'unix ms'). Defaults to all.")
+        .optional()
+        .details("The format to target ('iso', 'rfc2822', 'us', 'eu', 'unix', or 'unix ms'). Shows every format if left out"),
+    OptionSpec::string("order", "How to read dates like '05/07/2023' ('dmy' or 'mdy'). Defaults to 'dmy'.")
+        .optional()
+        .details("How to read dates where the day and month could be either way round, like '05/07/2023' ('dmy' or 'mdy'). Defaults to 'dmy'"),
+]);
+
+pub fn run(value: String, target: Option<String>, order: Option<String>) -> String
+{
+    convert(value, target, order, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+/// Without a target, every format is listed instead.
+pub fn convert(
+    value: String,
+    target: Option<String>,
+    order: Option<String>,
+    locale: Locale,
+) -> Reply
+{
+    let order = match order.map(|x| DateOrder::from_str(&x)).transpose()
+    {
+        Ok(x) => x.unwrap_or_default(),
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    let target = match target.map(|x| DateFormat::from_str(&x)).transpose()
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    let date = match Date::parse(&value, order)
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    let mut notes = vec![date.describe(locale)];
+    if date.ambiguous
+    {
+        notes.push(locale.text(
+            "date.ambiguous",
+            &[("date", value.trim()), ("order", &order.name(locale))],
+        ));
+    }
+    match target
+    {
+        Some(target) => Reply::Conversion(Conversion {
+            input: date.to_string(),
+            output: date.format(target),
+            notes,
+            ..Default::default()
+        }),
+        None => Reply::Text(
+            DateFormat::ALL
+                .iter()
+                .map(|&format| format!("{format}: {}", date.format(format)))
+                .chain(notes)
+                .collect::<Vec<_>>()
+                .join("\n"),
+        ),
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum ParseDateError
+{
+    Unrecognised(String),
+    OutOfRange(String),
+    InvalidOrder(String),
+    InvalidFormat(String),
+    Time(#[from] ParseTimeError),
+}
+
+impl ParseDateError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::Unrecognised(date) => locale.text("error.date.unrecognised", &[("date", date)]),
+            Self::OutOfRange(date) => locale.text("error.date.out-of-range", &[("date", date)]),
+            Self::InvalidOrder(order) =>
+            {
+                locale.text("error.date.invalid-order", &[("order", order)])
+            }
+            Self::InvalidFormat(format) =>
+            {
+                locale.text("error.date.invalid-format", &[("format", format)])
+            }
+            Self::Time(e) => e.text(locale),
+        }
+    }
+}
+
+/// Which way round to read dates like '05/07/2023'.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder
+{
+    #[default]
+    DayMonthYear,
+    MonthDayYear,
+}
+
+impl FromStr for DateOrder
+{
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        match &*s.trim().to_lowercase()
+        {
+            "dmy" | "d/m/y" | "eu" | "uk" => Ok(Self::DayMonthYear),
+            "mdy" | "m/d/y" | "us" => Ok(Self::MonthDayYear),
+            _ => Err(ParseDateError::InvalidOrder(s.trim().to_string())),
+        }
+    }
+}
+
+impl DateOrder
+{
+    /// The order's name in `locale` (e.g. 'day/month/year').
+    pub fn name(self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::DayMonthYear => locale.text("date.day-month-year", &[]),
+            Self::MonthDayYear => locale.text("date.month-day-year", &[]),
+        }
+    }
+}
+
+impl fmt::Display for DateOrder
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+/// The formats a date can be shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateFormat
+{
+    Iso8601,
+    Rfc2822,
+    Us,
+    Eu,
+    Unix,
+    UnixMillis,
+}
+
+impl DateFormat
+{
+    pub const ALL: [Self; 6] = [
+        Self::Iso8601,
+        Self::Rfc2822,
+        Self::Us,
+        Self::Eu,
+        Self::Unix,
+        Self::UnixMillis,
+    ];
+}
+
+impl FromStr for DateFormat
+{
+    type Err = ParseDateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let name: String = s
+            .to_lowercase()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_' | '(' | ')'))
+            .collect();
+        match &*name
+        {
+            "iso" | "iso8601" => Ok(Self::Iso8601),
+            "rfc2822" | "email" => Ok(Self::Rfc2822),
+            "us" | "mdy" => Ok(Self::Us),
+            "eu" | "uk" | "dmy" => Ok(Self::Eu),
+            "unix" | "epoch" | "unixs" | "seconds" => Ok(Self::Unix),
+            "unixms" | "ms" | "millis" | "milliseconds" => Ok(Self::UnixMillis),
+            _ => Err(ParseDateError::InvalidFormat(s.trim().to_string())),
+        }
+    }
+}
+
+impl fmt::Display for DateFormat
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let name = match self
+        {
+            Self::Iso8601 => "ISO 8601",
+            Self::Rfc2822 => "RFC 2822",
+            Self::Us => "US",
+            Self::Eu => "EU",
+            Self::Unix => "Unix",
+            Self::UnixMillis => "Unix (ms)",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+const MONTHS: [&str; 12] = [
+    "january",
+    "february",
+    "march",
+    "april",
+    "may",
+    "june",
+    "july",
+    "august",
+    "september",
+    "october",
+    "november",
+    "december",
+];
+
+const WEEKDAYS: [&str; 7] = [
+    "monday",
+    "tuesday",
+    "wednesday",
+    "thursday",
+    "friday",
+    "saturday",
+    "sunday",
+];
+
+/// A date, optionally with a time. Dates without an offset are in UTC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Date
+{
+    pub datetime: DateTime<FixedOffset>,
+
+    /// Whether a time was given, rather than taking midnight
+    pub has_time: bool,
+
+    /// Whether a year was given, rather than taking the current one
+    pub has_year: bool,
+
+    /// Whether the date was given with an offset (or as a Unix timestamp),
+    /// rather than being taken as UTC
+    pub has_offset: bool,
+
+    /// Whether the day and month could have been either way round
+    pub ambiguous: bool,
+}
+
+impl Date
+{
+    /// Parse a date in one of many formats, using `order` for dates like
+    /// '05/07/2023' where either way round is valid. Dates may be followed
+    /// by a time (e.g. '15/07/2023 3pm').
+    pub fn parse(s: &str, order: DateOrder) -> Result<Self, ParseDateError>
+    {
+        let s = s.trim();
+        let utc = FixedOffset::east_opt(0).expect("UTC is a valid offset");
+        let with_time = |datetime| Self {
+            datetime,
+            has_time: true,
+            has_year: true,
+            has_offset: true,
+            ambiguous: false,
+        };
+
+        if let Some(datetime) = parse_unix(s)
+        {
+            return Ok(with_time(datetime.with_timezone(&utc)));
+        }
+        if let Ok(datetime) =
+            DateTime::parse_from_rfc3339(s).or_else(|_| DateTime::parse_from_rfc2822(s))
+        {
+            return Ok(with_time(datetime));
+        }
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"]
+        {
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format)
+            {
+                return Ok(Self {
+                    has_offset: false,
+                    ..with_time(utc.from_utc_datetime(&datetime))
+                });
+            }
+        }
+
+        // Take the longest date that parses, with anything after it as the
+        // time (e.g. '15 July 2023 at 3pm')
+        let words: Vec<&str> = s.split_whitespace().collect();
+        for i in (1..=words.len()).rev()
+        {
+            let Some(parsed) = parse_date(&words[..i].join(" "), order)?
+            else
+            {
+                continue;
+            };
+
+            let rest = words[i..].join(" ");
+            let rest = rest.strip_prefix("at ").unwrap_or(&rest).trim();
+            let time = match rest
+            {
+                "" => None,
+                rest => Some(Time::from_str(rest)?),
+            };
+
+            let date = parsed.date;
+            let datetime = date.and_time(time.map(|x| x.as_naive()).unwrap_or_default());
+            return Ok(Self {
+                datetime: utc.from_utc_datetime(&datetime),
+                has_time: time.is_some(),
+                has_year: parsed.has_year,
+                has_offset: false,
+                ambiguous: parsed.ambiguous,
+            });
+        }
+
+        Err(ParseDateError::Unrecognised(s.to_string()))
+    }
+
+    /// Show the date in `format`.
+    pub fn format(&self, format: DateFormat) -> String
+    {
+        let datetime = self.datetime;
+        match format
+        {
+            DateFormat::Iso8601 if self.has_time => datetime.to_rfc3339(),
+            DateFormat::Iso8601 => datetime.format("%Y-%m-%d").to_string(),
+            DateFormat::Rfc2822 => datetime.to_rfc2822(),
+            DateFormat::Us if self.has_time => datetime.format("%m/%d/%Y %I:%M:%S %p").to_string(),
+            DateFormat::Us => datetime.format("%m/%d/%Y").to_string(),
+            DateFormat::Eu if self.has_time => datetime.format("%d/%m/%Y %H:%M:%S").to_string(),
+            DateFormat::Eu => datetime.format("%d/%m/%Y").to_string(),
+            DateFormat::Unix => datetime.timestamp().to_string(),
+            DateFormat::UnixMillis => datetime.timestamp_millis().to_string(),
+        }
+    }
+
+    /// The day of the week, ISO week and day of the year in `locale` (e.g.
+    /// 'Saturday, ISO week 2023-W28, day 196 of 365').
+    pub fn describe(&self, locale: Locale) -> String
+    {
+        let date = self.datetime.date_naive();
+        let week = date.iso_week();
+        let days = if NaiveDate::from_ymd_opt(date.year(), 2, 29).is_some()
+        {
+            366
+        }
+        else
+        {
+            365
+        };
+
+        let weekday = format!("weekday.{}", date.format("%A")).to_lowercase();
+        locale.text(
+            "date.description",
+            &[
+                ("weekday", &locale.text(&weekday, &[])),
+                ("week", &format!("{}-W{:02}", week.year(), week.week())),
+                ("day", &date.ordinal().to_string()),
+                ("days", &days.to_string()),
+            ],
+        )
+    }
+}
+
+impl fmt::Display for Date
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.format(DateFormat::Iso8601))
+    }
+}
+
+/// Parse a Unix timestamp. Anything with 12 or more digits is taken as
+/// milliseconds, and shorter ones need at least 9 digits (or an '@' in front)
+/// so that they aren't mistaken for dates like '20230715'.
+fn parse_unix(s: &str) -> Option<DateTime<Utc>>
+{
+    let (digits, explicit) = match s.strip_prefix('@')
+    {
+        Some(s) => (s, true),
+        None => (s, false),
+    };
+    let count = digits.trim_start_matches('-').len();
+    if count == 0
+        || !digits
+            .trim_start_matches('-')
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let value: i64 = digits.parse().ok()?;
+    match count
+    {
+        12.. => Utc.timestamp_millis_opt(value).single(),
+        9.. => Utc.timestamp_opt(value, 0).single(),
+        _ if explicit => Utc.timestamp_opt(value, 0).single(),
+        _ => None,
+    }
+}
+
+struct ParsedDate
+{
+    date: NaiveDate,
+    has_year: bool,
+    ambiguous: bool,
+}
+
+/// Parse a date on its own, returning `None` if it isn't a date at all and an
+/// error if it is one that doesn't exist (e.g. '31/02/2023').
+fn parse_date(s: &str, order: DateOrder) -> Result<Option<ParsedDate>, ParseDateError>
+{
+    let parsed = parse_numeric_date(s, order).or_else(|| parse_text_date(s));
+    let Some((year, month, day, has_year, ambiguous)) = parsed
+    else
+    {
+        return Ok(None);
+    };
+
+    let year = match year
+    {
+        Some(year) => year,
+        None => Utc::now().year(),
+    };
+    match NaiveDate::from_ymd_opt(year, month, day)
+    {
+        Some(date) => Ok(Some(ParsedDate {
+            date,
+            has_year,
+            ambiguous,
+        })),
+        None => Err(ParseDateError::OutOfRange(s.to_string())),
+    }
+}
+
+type DateParts = (Option<i32>, u32, u32, bool, bool);
+
+/// Parse dates like '2023-07-15', '15/07/2023', '07.15.23', '15/07' and
+/// '20230715'.
+fn parse_numeric_date(s: &str, order: DateOrder) -> Option<DateParts>
+{
+    if s.len() == 8 && s.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Some((
+            Some(s[..4].parse().ok()?),
+            s[4..6].parse().ok()?,
+            s[6..].parse().ok()?,
+            true,
+            false,
+        ));
+    }
+
+    let parts: Vec<&str> = s.split(['/', '.', '-']).collect();
+    if !(2..=3).contains(&parts.len())
+        || parts
+            .iter()
+            .any(|x| x.is_empty() || !x.bytes().all(|b| b.is_ascii_digit()))
+    {
+        return None;
+    }
+
+    if parts[0].len() == 4
+    {
+        let [year, month, day] = parts[..]
+        else
+        {
+            return None;
+        };
+        return Some((
+            Some(year.parse().ok()?),
+            month.parse().ok()?,
+            day.parse().ok()?,
+            true,
+            false,
+        ));
+    }
+
+    let first: u32 = parts[0].parse().ok()?;
+    let second: u32 = parts[1].parse().ok()?;
+    let year = match parts.get(2)
+    {
+        Some(year) if year.len() <= 2 =>
+        {
+            let year: i32 = year.parse().ok()?;
+            Some(if year < 70 { 2000 + year } else { 1900 + year })
+        }
+        Some(year) => Some(year.parse().ok()?),
+        None => None,
+    };
+
+    let order = match (first, second)
+    {
+        (13.., ..=12) => DateOrder::DayMonthYear,
+        (..=12, 13..) => DateOrder::MonthDayYear,
+        _ => order,
+    };
+    let ambiguous = first <= 12 && second <= 12 && first != second;
+    let (month, day) = match order
+    {
+        DateOrder::DayMonthYear => (second, first),
+        DateOrder::MonthDayYear => (first, second),
+    };
+
+    Some((year, month, day, year.is_some(), ambiguous))
+}
+
+/// Parse dates with the month in words (e.g. '15 July 2023', 'Sat, Jul 15th
+/// 2023', 'Dec 25').
+fn parse_text_date(s: &str) -> Option<DateParts>
+{
+    let s = s.to_lowercase();
+    let mut month = None;
+    let mut numbers = Vec::new();
+    for word in s.split([' ', ',']).filter(|x| !x.is_empty() && *x != "of")
+    {
+        let word = word.trim_end_matches('.');
+        let name = |names: &[&str]| {
+            names
+                .iter()
+                .position(|name| name == &word || (word.len() >= 3 && name.starts_with(word)))
+        };
+
+        if let Some(x) = name(&MONTHS)
+        {
+            if month.replace(x as u32 + 1).is_some()
+            {
+                return None;
+            }
+        }
+        else if name(&WEEKDAYS).is_some()
+        {
+            continue;
+        }
+        else
+        {
+            let number = ["st", "nd", "rd", "th"].iter().fold(word, |word, suffix| {
+                word.strip_suffix(suffix).unwrap_or(word)
+            });
+            if number.is_empty() || !number.bytes().all(|b| b.is_ascii_digit())
+            {
+                return None;
+            }
+            numbers.push(number);
+        }
+    }
+
+    let month = month?;
+    let day = numbers.iter().find(|x| x.len() <= 2)?;
+    let year = numbers.iter().find(|x| x.len() == 4);
+    if numbers.len() != 1 + year.is_some() as usize
+    {
+        return None;
+    }
+
+    let year = match year
+    {
+        Some(year) => Some(year.parse().ok()?),
+        None => None,
+    };
+    Some((year, month, day.parse().ok()?, year.is_some(), false))
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn parse(s: &str) -> Date { Date::parse(s, DateOrder::DayMonthYear).unwrap() }
+
+    #[test]
+    fn test_parse_numeric()
+    {
+        assert_eq!(parse("2023-07-15").to_string(), "2023-07-15");
+        assert_eq!(parse("20230715").to_string(), "2023-07-15");
+        assert_eq!(parse("15/07/2023").to_string(), "2023-07-15");
+        assert_eq!(parse("07/15/2023").to_string(), "2023-07-15");
+        assert_eq!(parse("15.07.23").to_string(), "2023-07-15");
+
+        let date = parse("05/07/2023");
+        assert_eq!(date.to_string(), "2023-07-05");
+        assert!(date.ambiguous);
+        let date = Date::parse("05/07/2023", DateOrder::MonthDayYear).unwrap();
+        assert_eq!(date.to_string(), "2023-05-07");
+
+        assert!(matches!(
+            Date::parse("31/02/2023", DateOrder::DayMonthYear),
+            Err(ParseDateError::OutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_text()
+    {
+        assert_eq!(parse("15 July 2023").to_string(), "2023-07-15");
+        assert_eq!(parse("Saturday, July 15th, 2023").to_string(), "2023-07-15");
+        assert_eq!(parse("15th of Jul 2023").to_string(), "2023-07-15");
+
+        let date = parse("Dec 25");
+        assert!(!date.has_year);
+        assert!(Date::parse("15 Julember 2023", DateOrder::DayMonthYear).is_err());
+    }
+
+    #[test]
+    fn test_parse_with_time()
+    {
+        let date = parse("15/07/2023 at 2.30pm");
+        assert!(date.has_time);
+        assert_eq!(date.to_string(), "2023-07-15T14:30:00+00:00");
+
+        assert_eq!(
+            parse("2023-07-15T14:30:00+02:00").format(DateFormat::Unix),
+            "1689424200"
+        );
+        assert_eq!(
+            parse("Sat, 15 Jul 2023 14:30:00 +0000").format(DateFormat::Eu),
+            "15/07/2023 14:30:00"
+        );
+    }
+
+    #[test]
+    fn test_unix()
+    {
+        assert_eq!(parse("1689379200").to_string(), "2023-07-15T00:00:00+00:00");
+        assert_eq!(
+            parse("1689379200000").format(DateFormat::Unix),
+            "1689379200"
+        );
+        assert_eq!(parse("@0").format(DateFormat::Us), "01/01/1970 12:00:00 AM");
+    }
+
+    #[test]
+    fn test_run()
+    {
+        assert_eq!(
+            run("15 July 2023".to_string(), Some("us".to_string()), None),
+            "2023-07-15 -> 07/15/2023\nSaturday, ISO week 2023-W28, day 196 of 365"
+        );
+        assert_eq!(
+            run("2024-12-30".to_string(), Some("rfc2822".to_string()), None),
+            "2024-12-30 -> Mon, 30 Dec 2024 00:00:00 +0000\nMonday, ISO week 2025-W01, day 365 of 366"
+        );
+        assert!(run("05/07/2023".to_string(), None, None).contains("read as day/month/year"));
+        assert!(
+            run("15 July 2023".to_string(), Some("julian".to_string()), None).starts_with("Error")
+        );
+    }
+}
@@ -0,0 +1,590 @@
+use crate::{
+    i18n::Locale,
+    quantity::{LinearUnit, ParseUnitError, Quantity},
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+    time::{format_duration, Time},
+};
+use chrono::Duration;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `duration` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "duration",
+    "Convert durations, add them to times, or find the time between two times.",
+)
+.details("Convert durations between units, add them to or subtract them from times, or find the time between two times")
+.options(&[
+    OptionSpec::string("input", "e.g. '1h30m', 'PT1H30M', '14:30 + 2h45m', 'from 09:15 to 17:40'.")
+        .details("A duration or calculation (e.g. '1h30m', '2 days 3 hours', 'PT1H30M', '14:30 + 2h45m', '17:40 - 09:15', 'from 09:15 to 17:40')"),
+    OptionSpec::string("target", "The unit to target. (e.g 'minutes', 'hours', 'days').")
+        .optional()
+        .details("The unit to show a duration in (e.g 'minutes', 'hours', 'days'). Shows it in words if left out"),
+]);
+
+pub fn run(input: String, target: Option<String>) -> String
+{
+    convert(input, target, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+pub fn convert(input: String, target: Option<String>, locale: Locale) -> Reply
+{
+    let target = match target.map(|x| DurationUnit::from_str(&x)).transpose()
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    let (source, output) = match evaluate(&input, locale)
+    {
+        Ok((source, Value::Time(time, days))) =>
+        {
+            let day = match days
+            {
+                0 => String::new(),
+                1 => locale.text("day-offset.next", &[]),
+                -1 => locale.text("day-offset.previous", &[]),
+                days => locale.text("day-offset.days", &[("days", &format!("{days:+}"))]),
+            };
+            (source, format!("{time}{day}"))
+        }
+        Ok((source, Value::Duration(duration))) => match target
+        {
+            Some(unit) =>
+            {
+                let mut quantity = Quantity::new(seconds(duration), DurationUnit::Second);
+                quantity.into_unit(unit);
+                (source, quantity.text(locale))
+            }
+            None => (
+                source,
+                format!(
+                    "{} ({})",
+                    format_duration(duration),
+                    humanise(duration, locale)
+                ),
+            ),
+        },
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    Reply::Conversion(Conversion {
+        input: source,
+        output,
+        ..Default::default()
+    })
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum ParseDurationError
+{
+    InvalidDuration(String),
+    MissingUnit(String),
+    NonFixedUnit(String),
+    InvalidCalculation(String),
+    Unit(#[from] ParseUnitError),
+}
+
+impl ParseDurationError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::InvalidDuration(input) =>
+            {
+                locale.text("error.duration.invalid", &[("input", input)])
+            }
+            Self::MissingUnit(number) =>
+            {
+                locale.text("error.duration.missing-unit", &[("number", number)])
+            }
+            Self::NonFixedUnit(input) =>
+            {
+                locale.text("error.duration.non-fixed-unit", &[("input", input)])
+            }
+            Self::InvalidCalculation(input) =>
+            {
+                locale.text("error.duration.invalid-calculation", &[("input", input)])
+            }
+            Self::Unit(e) => e.text(locale),
+        }
+    }
+}
+
+/// Units of time, for converting durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DurationUnit
+{
+    Millisecond,
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Week,
+}
+
+impl LinearUnit for DurationUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::Millisecond,
+        Self::Second,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::Week,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::Millisecond => 1e-3,
+            Self::Second => 1.0,
+            Self::Minute => 60.0,
+            Self::Hour => 3600.0,
+            Self::Day => 86400.0,
+            Self::Week => 604800.0,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Millisecond => "ms",
+            Self::Second => "s",
+            Self::Minute => "min",
+            Self::Hour => "h",
+            Self::Day => "d",
+            Self::Week => "wk",
+        }
+    }
+}
+
+impl FromStr for DurationUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "ms" | "msec" | "msecs" | "millisecond" | "milliseconds" => Self::Millisecond,
+            "s" | "sec" | "secs" | "second" | "seconds" => Self::Second,
+            "m" | "min" | "mins" | "minute" | "minutes" => Self::Minute,
+            "h" | "hr" | "hrs" | "hour" | "hours" => Self::Hour,
+            "d" | "day" | "days" => Self::Day,
+            "w" | "wk" | "wks" | "week" | "weeks" => Self::Week,
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for DurationUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+fn seconds(duration: Duration) -> f64 { duration.num_milliseconds() as f64 / 1000.0 }
+
+/// Turn a number of seconds into a duration, rejecting ones too long to be of
+/// any use.
+fn from_seconds(seconds: f64, source: &str) -> Result<Duration, ParseDurationError>
+{
+    // About 30 million years
+    if !seconds.is_finite() || seconds.abs() > 1e15
+    {
+        return Err(ParseDurationError::InvalidDuration(
+            source.trim().to_string(),
+        ));
+    }
+    Ok(Duration::milliseconds((seconds * 1000.0).round() as i64))
+}
+
+/// Parse a duration, such as '1h30m', '90 minutes', '2 days, 3 hours and 5
+/// minutes', '1:30:00' or 'PT1H30M' (ISO 8601).
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError>
+{
+    let lower = s.trim().to_lowercase();
+    let invalid = || ParseDurationError::InvalidDuration(s.trim().to_string());
+    if lower.is_empty()
+    {
+        return Err(invalid());
+    }
+
+    if let Some(iso) = lower.strip_prefix('p')
+    {
+        return parse_iso(iso, s);
+    }
+
+    if lower.contains(':')
+    {
+        let sections: Vec<&str> = lower.split(':').collect();
+        if sections.len() > 3
+        {
+            return Err(invalid());
+        }
+
+        let mut total = 0.0;
+        for (section, factor) in sections.iter().zip([3600.0, 60.0, 1.0])
+        {
+            let value: f64 = section.trim().parse().map_err(|_| invalid())?;
+            total += value * factor;
+        }
+        return from_seconds(total, s);
+    }
+
+    let mut total = 0.0;
+    let mut rest = lower.as_str();
+    loop
+    {
+        rest = rest.trim_start_matches([' ', ',']);
+        rest = rest.strip_prefix("and ").unwrap_or(rest);
+        if rest.is_empty()
+        {
+            break;
+        }
+
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let number: f64 = rest[..number_end].parse().map_err(|_| invalid())?;
+        rest = rest[number_end..].trim_start();
+
+        let unit_end = rest
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(rest.len());
+        if unit_end == 0
+        {
+            return Err(ParseDurationError::MissingUnit(number.to_string()));
+        }
+        total += number * DurationUnit::from_str(&rest[..unit_end])?.factor();
+        rest = &rest[unit_end..];
+    }
+
+    from_seconds(total, s)
+}
+
+/// Parse the part of an ISO 8601 duration after the 'P' (e.g. '2DT3H30M').
+fn parse_iso(s: &str, source: &str) -> Result<Duration, ParseDurationError>
+{
+    let invalid = || ParseDurationError::InvalidDuration(source.trim().to_string());
+    let (date, time) = s.split_once('t').unwrap_or((s, ""));
+    if s.is_empty() || s.ends_with('t')
+    {
+        return Err(invalid());
+    }
+
+    let mut total = 0.0;
+    for (part, is_time) in [(date, false), (time, true)]
+    {
+        let mut number = String::new();
+        for c in part.chars()
+        {
+            if c.is_ascii_digit() || c == '.' || c == ','
+            {
+                number.push(if c == ',' { '.' } else { c });
+                continue;
+            }
+
+            let value: f64 = number.parse().map_err(|_| invalid())?;
+            number.clear();
+            total += value
+                * match (c, is_time)
+                {
+                    ('y', false) | ('m', false) =>
+                    {
+                        return Err(ParseDurationError::NonFixedUnit(source.trim().to_string()))
+                    }
+                    ('w', false) => 604800.0,
+                    ('d', false) => 86400.0,
+                    ('h', true) => 3600.0,
+                    ('m', true) => 60.0,
+                    ('s', true) => 1.0,
+                    _ => return Err(invalid()),
+                };
+        }
+        if !number.is_empty()
+        {
+            return Err(invalid());
+        }
+    }
+
+    from_seconds(total, source)
+}
+
+/// Describe a duration in words in `locale` (e.g. '1 day, 3 hours and 5
+/// minutes').
+pub fn humanise(duration: Duration, locale: Locale) -> String
+{
+    let millis = duration.num_milliseconds().abs();
+
+    let mut parts = Vec::new();
+    let mut rest = millis;
+    for (name, size) in [
+        ("week", 604_800_000),
+        ("day", 86_400_000),
+        ("hour", 3_600_000),
+        ("minute", 60_000),
+        ("second", 1000),
+        ("millisecond", 1),
+    ]
+    {
+        let amount = rest / size;
+        rest %= size;
+        if amount > 0
+        {
+            parts.push(amount_text(amount, name, locale));
+        }
+    }
+
+    let text = match parts.len()
+    {
+        0 => return amount_text(0, "second", locale),
+        1 => parts.remove(0),
+        n => locale.text(
+            "list.and",
+            &[
+                ("list", &parts[..n - 1].join(", ")),
+                ("last", &parts[n - 1]),
+            ],
+        ),
+    };
+    if duration < Duration::zero()
+    {
+        locale.text("duration.negative", &[("duration", &text)])
+    }
+    else
+    {
+        text
+    }
+}
+
+/// An amount of a unit in words (e.g. '3 hours').
+fn amount_text(amount: i64, name: &str, locale: Locale) -> String
+{
+    let key = if amount == 1
+    {
+        format!("duration.{name}")
+    }
+    else
+    {
+        format!("duration.{name}s")
+    };
+    locale.text(&key, &[("amount", &amount.to_string())])
+}
+
+/// The result of a calculation: a time (with the number of days it moved by)
+/// or a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Value
+{
+    Time(Time, i64),
+    Duration(Duration),
+}
+
+impl Value
+{
+    fn parse(s: &str) -> Result<Self, ParseDurationError>
+    {
+        // Durations are tried first, so that '1h' isn't taken as a time
+        match parse_duration(s)
+        {
+            Ok(x) if !s.contains(':') => Ok(Self::Duration(x)),
+            result => match Time::from_str(s)
+            {
+                Ok(time) => Ok(Self::Time(time, 0)),
+                Err(_) => result.map(Self::Duration),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Value
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Time(time, _) => write!(f, "{time}"),
+            Self::Duration(duration) => write!(f, "{}", format_duration(*duration)),
+        }
+    }
+}
+
+/// Evaluate a duration (e.g. '1h30m'), the time between two times (e.g.
+/// 'from 09:15 to 17:40'), or a sum (e.g. '14:30 + 2h45m'). Returns the input
+/// as understood, along with the result.
+fn evaluate(input: &str, locale: Locale) -> Result<(String, Value), ParseDurationError>
+{
+    let input = input.trim();
+    let range = input.strip_prefix("from ").unwrap_or(input);
+    if let Some((start, end)) = range
+        .split_once(" to ")
+        .or_else(|| range.split_once(" until "))
+    {
+        if let (Ok(start), Ok(end)) = (Time::from_str(start.trim()), Time::from_str(end.trim()))
+        {
+            return Ok((
+                locale.text(
+                    "duration.range",
+                    &[("start", &start.to_string()), ("end", &end.to_string())],
+                ),
+                Value::Duration(start.until(end)),
+            ));
+        }
+    }
+
+    // Split on the operators, keeping them with the term that follows
+    let mut terms = vec![('+', String::new())];
+    for word in input.split_whitespace()
+    {
+        match word
+        {
+            "+" | "-" => terms.push((word.chars().next().unwrap(), String::new())),
+            _ =>
+            {
+                let term = &mut terms.last_mut().unwrap().1;
+                if !term.is_empty()
+                {
+                    term.push(' ');
+                }
+                term.push_str(word);
+            }
+        }
+    }
+
+    let mut source = String::new();
+    let mut result: Option<Value> = None;
+    for (operator, term) in terms
+    {
+        let value = Value::parse(&term)?;
+        if result.is_some()
+        {
+            source.push_str(&format!(" {operator} "));
+        }
+        source.push_str(&value.to_string());
+
+        result = Some(match (result, value, operator)
+        {
+            (None, value, _) => value,
+            (Some(Value::Duration(a)), Value::Duration(b), '+') => Value::Duration(a + b),
+            (Some(Value::Duration(a)), Value::Duration(b), _) => Value::Duration(a - b),
+            (Some(Value::Time(time, days)), Value::Duration(duration), operator) =>
+            {
+                let duration = if operator == '+' { duration } else { -duration };
+                let (time, moved) = time.overflowing_add(duration);
+                Value::Time(time, days + moved)
+            }
+            (Some(Value::Duration(duration)), Value::Time(time, days), '+') =>
+            {
+                let (time, moved) = time.overflowing_add(duration);
+                Value::Time(time, days + moved)
+            }
+            (Some(Value::Time(a, _)), Value::Time(b, _), '-') => Value::Duration(a - b),
+            // Something like '14:30 + 2:45', where the second time is a duration
+            (Some(Value::Time(time, days)), Value::Time(duration, _), _) =>
+            {
+                let duration = duration - Time::new(duration.kind());
+                let (time, moved) = time.overflowing_add(duration);
+                Value::Time(time, days + moved)
+            }
+            _ => return Err(ParseDurationError::InvalidCalculation(input.to_string())),
+        });
+    }
+
+    Ok((source, result.expect("there is always a term")))
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_parse_duration()
+    {
+        assert_eq!(parse_duration("1h30m").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_duration("90 minutes").unwrap(), Duration::minutes(90));
+        assert_eq!(parse_duration("PT1H30M").unwrap(), Duration::minutes(90));
+        assert_eq!(
+            parse_duration("2 days 3 hours").unwrap(),
+            Duration::hours(51)
+        );
+        assert_eq!(
+            parse_duration("1 day, 2 hours and 30 secs").unwrap(),
+            Duration::seconds(93630)
+        );
+        assert_eq!(
+            parse_duration("P1W2DT0.5S").unwrap(),
+            Duration::milliseconds(777_600_500)
+        );
+        assert_eq!(parse_duration("1:30").unwrap(), Duration::minutes(90));
+
+        assert!(matches!(
+            parse_duration("90"),
+            Err(ParseDurationError::MissingUnit(_))
+        ));
+        assert!(matches!(
+            parse_duration("P1M"),
+            Err(ParseDurationError::NonFixedUnit(_))
+        ));
+        assert!(parse_duration("1 fortnight").is_err());
+        assert!(parse_duration("PT").is_err());
+    }
+
+    #[test]
+    fn test_humanise()
+    {
+        assert_eq!(
+            humanise(Duration::minutes(90), Locale::English),
+            "1 hour and 30 minutes"
+        );
+        assert_eq!(
+            humanise(Duration::seconds(93905), Locale::English),
+            "1 day, 2 hours, 5 minutes and 5 seconds"
+        );
+        assert_eq!(humanise(Duration::zero(), Locale::English), "0 seconds");
+        assert_eq!(
+            humanise(Duration::minutes(-1), Locale::English),
+            "minus 1 minute"
+        );
+    }
+
+    #[test]
+    fn test_run()
+    {
+        assert_eq!(
+            run("14:30 + 2h45m".to_string(), None),
+            "14:30:00 + 2h 45m -> 17:15:00"
+        );
+        assert_eq!(
+            run("11pm + 90 minutes".to_string(), None),
+            "11:00:00 PM + 1h 30m -> 12:30:00 AM (next day)"
+        );
+        assert_eq!(
+            run("from 09:15 to 17:40".to_string(), None),
+            "09:15:00 to 17:40:00 -> 8h 25m (8 hours and 25 minutes)"
+        );
+        assert_eq!(
+            run("17:40 - 09:15".to_string(), Some("hours".to_string())),
+            "17:40:00 - 09:15:00 -> 8.42 Hour(s) [h]"
+        );
+        assert_eq!(
+            run("PT1H30M".to_string(), Some("min".to_string())),
+            "1h 30m -> 90.00 Minute(s) [min]"
+        );
+        assert_eq!(
+            run("14:30 + 2:45".to_string(), None),
+            "14:30:00 + 02:45:00 -> 17:15:00"
+        );
+        assert!(run("1h - 14:30".to_string(), None).starts_with("Error"));
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    i18n::Locale,
+    quantity::{self, LinearUnit, ParseUnitError, Quantity},
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+};
+use std::{fmt, str::FromStr};
+
+/// The `energy` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "energy",
+    "Convert from one energy unit to another. Supports J, kJ, cal, kcal, kWh, BTU, and eV.",
+)
+.options(&[
+    OptionSpec::string("value", "Original value (e.g. '250 kcal', '1.5 kWh')."),
+    OptionSpec::string(
+        "target",
+        "The unit to target. (e.g 'kJ' [kilojoules], 'BTU').",
+    ),
+]);
+
+/// The `energy` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "energy",
+    "Convert a number from one energy unit to another.",
+)
+.options(&quantity::typed_options::<EnergyUnit>());
+
+pub fn run(input: String, target: String) -> String { quantity::run::<EnergyUnit>(input, target) }
+
+pub fn convert(input: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert::<EnergyUnit>(input, target, locale)
+}
+
+pub fn convert_value(value: f64, unit: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert_value::<EnergyUnit>(value, unit, target, locale)
+}
+
+/// An amount of energy, stored in joules.
+pub type Energy = Quantity<EnergyUnit>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EnergyUnit
+{
+    Joule,
+    Kilojoule,
+
+    /// Thermochemical calorie
+    Calorie,
+
+    /// Kilocalorie, the 'calorie' on food labels
+    Kilocalorie,
+
+    KilowattHour,
+
+    /// International Table British thermal unit
+    Btu,
+
+    Electronvolt,
+}
+
+impl LinearUnit for EnergyUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::Joule,
+        Self::Kilojoule,
+        Self::Calorie,
+        Self::Kilocalorie,
+        Self::KilowattHour,
+        Self::Btu,
+        Self::Electronvolt,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::Joule => 1.0,
+            Self::Kilojoule => 1e3,
+            Self::Calorie => 4.184,
+            Self::Kilocalorie => 4184.0,
+            Self::KilowattHour => 3.6e6,
+            Self::Btu => 1055.05585262,
+            Self::Electronvolt => 1.602176634e-19,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Joule => "J",
+            Self::Kilojoule => "kJ",
+            Self::Calorie => "cal",
+            Self::Kilocalorie => "kcal",
+            Self::KilowattHour => "kWh",
+            Self::Btu => "BTU",
+            Self::Electronvolt => "eV",
+        }
+    }
+}
+
+impl FromStr for EnergyUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "j" | "joule" | "joules" => Self::Joule,
+            "kj" | "kilojoule" | "kilojoules" => Self::Kilojoule,
+            "cal" | "calorie" | "calories" => Self::Calorie,
+            "kcal" | "kilocalorie" | "kilocalories" => Self::Kilocalorie,
+            "kwh" | "kw h" | "kilowatt hour" | "kilowatt hours" | "kilowatt-hour"
+            | "kilowatt-hours" => Self::KilowattHour,
+            "btu" | "btus" => Self::Btu,
+            "ev" | "electronvolt" | "electronvolts" | "electron volt" | "electron volts" =>
+            {
+                Self::Electronvolt
+            }
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for EnergyUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_kwh_to_btu()
+    {
+        assert_eq!(
+            run("1 kWh".to_string(), "btu".to_string()),
+            "1.00 Kilowatt-hour(s) [kWh] -> 3412.14 British thermal unit(s) [BTU]"
+        );
+    }
+
+    #[test]
+    fn test_electronvolts_use_scientific_notation()
+    {
+        assert_eq!(
+            run("1 eV".to_string(), "J".to_string()),
+            "1.00 Electronvolt(s) [eV] -> 1.602e-19 Joule(s) [J]"
+        );
+    }
+}
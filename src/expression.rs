@@ -0,0 +1,842 @@
+use crate::{
+    area::AreaUnit,
+    currency::{Currency, CurrencyConverter, CurrencyType, ExchangeRates},
+    data::DataUnit,
+    energy::EnergyUnit,
+    i18n::Locale,
+    length::LengthUnit,
+    power::PowerUnit,
+    pressure::PressureUnit,
+    quantity::{format_value, LinearUnit},
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+    volume::VolumeUnit,
+};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `calculate` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new("calculate", "Calculate with units and convert the result.")
+.details("Calculate with units and convert the result. Supports lengths, areas, volumes, energy, power, pressure, data sizes, and currencies")
+.options(&[
+    OptionSpec::string("expression", "The calculation (e.g. '5 ft + 30 cm in m', '3 * 250 ml to cups', '$20 + €15 to GBP').")
+        .details("The calculation, optionally ending with 'in' or 'to' and a unit (e.g. '5 ft + 30 cm in m', '3 * 250 ml to cups', '$20 + €15 to GBP')"),
+]);
+
+pub fn run(converter: CurrencyConverter, input: String) -> (String, CurrencyConverter)
+{
+    let (reply, converter) = convert(converter, input, Locale::English);
+    (reply.to_string(), converter)
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+pub fn convert(
+    converter: CurrencyConverter,
+    input: String,
+    locale: Locale,
+) -> (Reply, CurrencyConverter)
+{
+    let expression = match Expression::from_str(&input)
+    {
+        Ok(x) => x,
+        Err(e) => return (Reply::Error(e.text(locale)), converter),
+    };
+
+    // Only bother the currency API when money is involved
+    let converter = if expression.uses_currency()
+    {
+        match Currency::refresh_exchange_rates(converter.clone())
+        {
+            Ok(x) => x,
+            Err(e) => return (Reply::Error(e.text(locale)), converter),
+        }
+    }
+    else
+    {
+        converter
+    };
+
+    match expression.evaluate(&converter.exchange_rates(), locale)
+    {
+        Ok(result) => (
+            Reply::Conversion(Conversion {
+                input: expression.source.clone(),
+                output: result,
+                source: expression.uses_currency().then_some("currencyapi.com"),
+                ..Default::default()
+            }),
+            converter,
+        ),
+        Err(e) => (Reply::Error(e.text(locale)), converter),
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum ExpressionError
+{
+    InvalidNumber(String),
+    UnknownUnit(String),
+    UnexpectedToken(String),
+    UnexpectedEnd,
+
+    /// The operation (e.g. 'add'), and the units on each side, which are
+    /// `None` for plain numbers
+    IncompatibleUnits
+    {
+        operation: &'static str,
+        left: Option<Unit>,
+        right: Option<Unit>,
+    },
+
+    DivisionByZero,
+}
+
+impl ExpressionError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        let describe = |unit: &Option<Unit>| match unit
+        {
+            Some(unit) => locale.text(
+                "expression.unit",
+                &[
+                    ("unit", &unit.name(locale)),
+                    ("dimension", &unit.dimension().name(locale)),
+                ],
+            ),
+            None => locale.text("expression.plain-number", &[]),
+        };
+        match self
+        {
+            Self::InvalidNumber(number) =>
+            {
+                locale.text("error.invalid-number", &[("number", number)])
+            }
+            Self::UnknownUnit(unit) =>
+            {
+                locale.text("error.expression.unknown-unit", &[("unit", unit)])
+            }
+            Self::UnexpectedToken(token) =>
+            {
+                locale.text("error.expression.unexpected-token", &[("token", token)])
+            }
+            Self::UnexpectedEnd => locale.text("error.expression.unexpected-end", &[]),
+            Self::IncompatibleUnits {
+                operation,
+                left,
+                right,
+            } => locale.text(
+                &format!("error.expression.{operation}"),
+                &[("left", &describe(left)), ("right", &describe(right))],
+            ),
+            Self::DivisionByZero => locale.text("error.expression.division-by-zero", &[]),
+        }
+    }
+}
+
+/// What a unit measures. Only units with the same dimension can be added,
+/// subtracted or converted between.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension
+{
+    Length,
+    Area,
+    Volume,
+    Energy,
+    Power,
+    Pressure,
+    Data,
+    Currency,
+}
+
+impl Dimension
+{
+    /// The dimension's name in `locale` (e.g. 'length').
+    pub fn name(self, locale: Locale) -> String
+    {
+        let key = match self
+        {
+            Self::Length => "dimension.length",
+            Self::Area => "dimension.area",
+            Self::Volume => "dimension.volume",
+            Self::Energy => "dimension.energy",
+            Self::Power => "dimension.power",
+            Self::Pressure => "dimension.pressure",
+            Self::Data => "dimension.data",
+            Self::Currency => "dimension.currency",
+        };
+
+        locale.text(key, &[])
+    }
+}
+
+impl fmt::Display for Dimension
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+/// Any unit that can appear in an expression.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Unit
+{
+    Length(LengthUnit),
+    Area(AreaUnit),
+    Volume(VolumeUnit),
+    Energy(EnergyUnit),
+    Power(PowerUnit),
+    Pressure(PressureUnit),
+    Data(DataUnit),
+    Currency(CurrencyType),
+}
+
+impl Unit
+{
+    /// The unit's name in `locale` (e.g. 'Metre(s) [m]').
+    pub fn name(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::Length(x) => x.name(locale),
+            Self::Area(x) => x.name(locale),
+            Self::Volume(x) => x.name(locale),
+            Self::Energy(x) => x.name(locale),
+            Self::Power(x) => x.name(locale),
+            Self::Pressure(x) => x.name(locale),
+            Self::Data(x) => x.name(locale),
+            Self::Currency(x) => x.name(locale),
+        }
+    }
+
+    pub fn dimension(&self) -> Dimension
+    {
+        match self
+        {
+            Self::Length(_) => Dimension::Length,
+            Self::Area(_) => Dimension::Area,
+            Self::Volume(_) => Dimension::Volume,
+            Self::Energy(_) => Dimension::Energy,
+            Self::Power(_) => Dimension::Power,
+            Self::Pressure(_) => Dimension::Pressure,
+            Self::Data(_) => Dimension::Data,
+            Self::Currency(_) => Dimension::Currency,
+        }
+    }
+
+    /// The number of base units of the dimension in one of this unit.
+    /// Currencies are based on the U.S. Dollar.
+    fn factor(&self, rates: &ExchangeRates) -> f64
+    {
+        match self
+        {
+            Self::Length(x) => x.factor(),
+            Self::Area(x) => x.factor(),
+            Self::Volume(x) => x.factor(),
+            Self::Energy(x) => x.factor(),
+            Self::Power(x) => x.factor(),
+            Self::Pressure(x) => x.factor(),
+            Self::Data(x) => x.bits(),
+            Self::Currency(x) => 1.0 / rates.rate(*x),
+        }
+    }
+}
+
+impl FromStr for Unit
+{
+    type Err = ExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        if let Ok(x) = s.parse()
+        {
+            Ok(Self::Length(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Area(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Volume(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Energy(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Power(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Pressure(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Data(x))
+        }
+        else if let Ok(x) = s.parse()
+        {
+            Ok(Self::Currency(x))
+        }
+        else
+        {
+            Err(ExpressionError::UnknownUnit(s.trim().to_string()))
+        }
+    }
+}
+
+impl fmt::Display for Unit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token
+{
+    Number(f64),
+    Unit(String),
+
+    /// A currency symbol written before its amount (e.g. '$20')
+    Prefix(CurrencyType),
+    Operator(char),
+    OpenParen,
+    CloseParen,
+}
+
+impl fmt::Display for Token
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Number(x) => write!(f, "{x}"),
+            Self::Unit(x) => write!(f, "{x}"),
+            Self::Prefix(x) => write!(f, "{x}"),
+            Self::Operator(x) => write!(f, "{x}"),
+            Self::OpenParen => write!(f, "("),
+            Self::CloseParen => write!(f, ")"),
+        }
+    }
+}
+
+const OPERATORS: &[char] = &['+', '-', '*', '/', '×', '÷'];
+const CURRENCY_SYMBOLS: &[char] = &['$', '€', '£', '¥'];
+
+fn tokenize(s: &str) -> Result<Vec<Token>, ExpressionError>
+{
+    let chars: Vec<char> = s.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len()
+    {
+        let c = chars[i];
+        match c
+        {
+            _ if c.is_whitespace() => i += 1,
+            '(' =>
+            {
+                tokens.push(Token::OpenParen);
+                i += 1;
+            }
+            ')' =>
+            {
+                tokens.push(Token::CloseParen);
+                i += 1;
+            }
+            _ if OPERATORS.contains(&c) =>
+            {
+                tokens.push(Token::Operator(match c
+                {
+                    '×' => '*',
+                    '÷' => '/',
+                    _ => c,
+                }));
+                i += 1;
+            }
+            _ if CURRENCY_SYMBOLS.contains(&c) =>
+            {
+                tokens.push(Token::Prefix(
+                    c.to_string()
+                        .parse()
+                        .map_err(|_| ExpressionError::UnknownUnit(c.to_string()))?,
+                ));
+                i += 1;
+            }
+            _ if c.is_ascii_digit() || c == '.' =>
+            {
+                let start = i;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.')
+                {
+                    i += 1;
+                }
+                // Exponent, but only if it's followed by digits so 'eV' stays a unit
+                if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E')
+                {
+                    let mut j = i + 1;
+                    if j < chars.len() && (chars[j] == '+' || chars[j] == '-')
+                    {
+                        j += 1;
+                    }
+                    if j < chars.len() && chars[j].is_ascii_digit()
+                    {
+                        i = j;
+                        while i < chars.len() && chars[i].is_ascii_digit()
+                        {
+                            i += 1;
+                        }
+                    }
+                }
+                let number: String = chars[start..i].iter().collect();
+                match number.parse()
+                {
+                    Ok(x) => tokens.push(Token::Number(x)),
+                    Err(_) => return Err(ExpressionError::InvalidNumber(number)),
+                }
+            }
+            _ =>
+            {
+                // Units run until the next operator or number, but may contain
+                // spaces ('fl oz') and exponents ('m2', 'm^2').
+                let start = i;
+                while i < chars.len()
+                    && !OPERATORS.contains(&chars[i])
+                    && !CURRENCY_SYMBOLS.contains(&chars[i])
+                    && chars[i] != '('
+                    && chars[i] != ')'
+                    && !(chars[i].is_ascii_digit()
+                        && !(chars[i - 1].is_alphabetic() || chars[i - 1] == '^'))
+                {
+                    i += 1;
+                }
+                let unit: String = chars[start..i].iter().collect();
+                tokens.push(Token::Unit(unit.trim().to_string()));
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Node
+{
+    Number(f64),
+    Quantity(f64, Unit),
+    Negate(Box<Node>),
+    Binary(Box<Node>, char, Box<Node>),
+}
+
+struct Parser
+{
+    tokens: Vec<Token>,
+    position: usize,
+}
+
+impl Parser
+{
+    fn peek(&self) -> Option<&Token> { self.tokens.get(self.position) }
+
+    fn next(&mut self) -> Result<Token, ExpressionError>
+    {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or(ExpressionError::UnexpectedEnd)?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn expression(&mut self) -> Result<Node, ExpressionError>
+    {
+        let mut node = self.term()?;
+        while let Some(Token::Operator(op @ ('+' | '-'))) = self.peek()
+        {
+            let op = *op;
+            self.position += 1;
+            node = Node::Binary(Box::new(node), op, Box::new(self.term()?));
+        }
+        Ok(node)
+    }
+
+    fn term(&mut self) -> Result<Node, ExpressionError>
+    {
+        let mut node = self.unary()?;
+        while let Some(Token::Operator(op @ ('*' | '/'))) = self.peek()
+        {
+            let op = *op;
+            self.position += 1;
+            node = Node::Binary(Box::new(node), op, Box::new(self.unary()?));
+        }
+        Ok(node)
+    }
+
+    fn unary(&mut self) -> Result<Node, ExpressionError>
+    {
+        match self.peek()
+        {
+            Some(Token::Operator('-')) =>
+            {
+                self.position += 1;
+                Ok(Node::Negate(Box::new(self.unary()?)))
+            }
+            Some(Token::Operator('+')) =>
+            {
+                self.position += 1;
+                self.unary()
+            }
+            _ => self.primary(),
+        }
+    }
+
+    fn primary(&mut self) -> Result<Node, ExpressionError>
+    {
+        match self.next()?
+        {
+            Token::OpenParen =>
+            {
+                let node = self.expression()?;
+                match self.next()?
+                {
+                    Token::CloseParen => Ok(node),
+                    token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+                }
+            }
+            Token::Number(_) | Token::Prefix(_) =>
+            {
+                self.position -= 1;
+                let mut node = self.quantity()?;
+                // Adjacent quantities are summed (e.g. '5 ft 10 in')
+                while matches!(node, Node::Quantity(..) | Node::Binary(..))
+                    && matches!(
+                        (self.peek(), self.tokens.get(self.position + 1)),
+                        (Some(Token::Prefix(_)), _)
+                            | (Some(Token::Number(_)), Some(Token::Unit(_)))
+                    )
+                {
+                    node = Node::Binary(Box::new(node), '+', Box::new(self.quantity()?));
+                }
+                Ok(node)
+            }
+            token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+
+    fn quantity(&mut self) -> Result<Node, ExpressionError>
+    {
+        match self.next()?
+        {
+            Token::Prefix(currency) => match self.next()?
+            {
+                Token::Number(x) => Ok(Node::Quantity(x, Unit::Currency(currency))),
+                token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+            },
+            Token::Number(x) => match self.peek()
+            {
+                Some(Token::Unit(unit)) =>
+                {
+                    let unit = unit.parse()?;
+                    self.position += 1;
+                    Ok(Node::Quantity(x, unit))
+                }
+                _ => Ok(Node::Number(x)),
+            },
+            token => Err(ExpressionError::UnexpectedToken(token.to_string())),
+        }
+    }
+}
+
+/// The result of evaluating (part of) an expression. The value is stored in
+/// the base unit of its dimension.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Value
+{
+    base: f64,
+    unit: Option<Unit>,
+}
+
+impl Value
+{
+    fn dimension(&self) -> Option<Dimension> { self.unit.map(|x| x.dimension()) }
+
+    fn incompatible(&self, operation: &'static str, other: &Self) -> ExpressionError
+    {
+        ExpressionError::IncompatibleUnits {
+            operation,
+            left: self.unit,
+            right: other.unit,
+        }
+    }
+}
+
+/// An arithmetic expression over quantities, optionally followed by a unit to
+/// convert the result to (e.g. '5 ft + 30 cm in m').
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expression
+{
+    /// The expression as written, without the target
+    source: String,
+    root: Node,
+    target: Option<Unit>,
+}
+
+impl Expression
+{
+    /// Parse `source`, converting the result to `target` if one is given.
+    fn parse(source: &str, target: Option<&str>) -> Result<Self, ExpressionError>
+    {
+        let target = match target
+        {
+            Some(x) => Some(x.trim().parse()?),
+            None => None,
+        };
+
+        let mut parser = Parser {
+            tokens: tokenize(source)?,
+            position: 0,
+        };
+        let root = parser.expression()?;
+        if let Some(token) = parser.peek()
+        {
+            return Err(ExpressionError::UnexpectedToken(token.to_string()));
+        }
+
+        Ok(Self {
+            source: source.trim().to_string(),
+            root,
+            target,
+        })
+    }
+
+    pub fn uses_currency(&self) -> bool
+    {
+        fn visit(node: &Node) -> bool
+        {
+            match node
+            {
+                Node::Number(_) => false,
+                Node::Quantity(_, unit) => unit.dimension() == Dimension::Currency,
+                Node::Negate(x) => visit(x),
+                Node::Binary(left, _, right) => visit(left) || visit(right),
+            }
+        }
+
+        visit(&self.root) || self.target.map(|x| x.dimension()) == Some(Dimension::Currency)
+    }
+
+    /// Evaluate the expression, converting the result to the target unit if
+    /// there is one. The result is written in `locale`.
+    pub fn evaluate(&self, rates: &ExchangeRates, locale: Locale)
+        -> Result<String, ExpressionError>
+    {
+        let value = Self::evaluate_node(&self.root, rates)?;
+        let unit = match self.target
+        {
+            Some(target) if value.dimension() == Some(target.dimension()) => Some(target),
+            Some(target) =>
+            {
+                return Err(value.incompatible(
+                    "convert",
+                    &Value {
+                        base: 0.0,
+                        unit: Some(target),
+                    },
+                ))
+            }
+            None => value.unit,
+        };
+
+        Ok(match unit
+        {
+            Some(unit) => format!(
+                "{} {}",
+                format_value(value.base / unit.factor(rates)),
+                unit.name(locale)
+            ),
+            None => format_value(value.base),
+        })
+    }
+
+    fn evaluate_node(node: &Node, rates: &ExchangeRates) -> Result<Value, ExpressionError>
+    {
+        Ok(match node
+        {
+            Node::Number(x) => Value {
+                base: *x,
+                unit: None,
+            },
+            Node::Quantity(x, unit) => Value {
+                base: x * unit.factor(rates),
+                unit: Some(*unit),
+            },
+            Node::Negate(x) =>
+            {
+                let value = Self::evaluate_node(x, rates)?;
+                Value {
+                    base: -value.base,
+                    ..value
+                }
+            }
+            Node::Binary(left, op, right) =>
+            {
+                let left = Self::evaluate_node(left, rates)?;
+                let right = Self::evaluate_node(right, rates)?;
+                match op
+                {
+                    '+' | '-' if left.dimension() == right.dimension() => Value {
+                        base: if *op == '+'
+                        {
+                            left.base + right.base
+                        }
+                        else
+                        {
+                            left.base - right.base
+                        },
+                        unit: left.unit,
+                    },
+                    '+' => return Err(left.incompatible("add", &right)),
+                    '-' => return Err(left.incompatible("subtract", &right)),
+                    '*' => match (left.unit, right.unit)
+                    {
+                        (None, unit) | (unit, None) => Value {
+                            base: left.base * right.base,
+                            unit,
+                        },
+                        (Some(Unit::Length(_)), Some(Unit::Length(_))) => Value {
+                            base: left.base * right.base,
+                            unit: Some(Unit::Area(AreaUnit::SquareMetre)),
+                        },
+                        _ => return Err(left.incompatible("multiply", &right)),
+                    },
+                    _ =>
+                    {
+                        if right.base == 0.0
+                        {
+                            return Err(ExpressionError::DivisionByZero);
+                        }
+                        match (left.dimension(), right.dimension())
+                        {
+                            (_, None) => Value {
+                                base: left.base / right.base,
+                                unit: left.unit,
+                            },
+                            (Some(a), Some(b)) if a == b => Value {
+                                base: left.base / right.base,
+                                unit: None,
+                            },
+                            _ => return Err(left.incompatible("divide", &right)),
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Find where the target unit might start (e.g. the ' in ' of '5 ft in m').
+/// It's only a target if both sides parse, since 'in' can also be inches.
+fn split_target(s: &str) -> (&str, Option<&str>)
+{
+    // ASCII lowercasing keeps byte offsets the same
+    let lower = s.to_ascii_lowercase();
+    match [" in ", " to ", "->"]
+        .iter()
+        .filter_map(|sep| lower.rfind(sep).map(|i| (i, sep.len())))
+        .max()
+    {
+        Some((i, len)) => (&s[..i], Some(&s[i + len..])),
+        None => (s, None),
+    }
+}
+
+impl FromStr for Expression
+{
+    type Err = ExpressionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim();
+        match split_target(s)
+        {
+            (source, Some(target)) => Self::parse(source, Some(target))
+                // e.g. '5 in + 3 in', where the ' in ' is inches
+                .or_else(|e| Self::parse(s, None).map_err(|_| e)),
+            (source, None) => Self::parse(source, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::currency::tests::converter;
+
+    fn evaluate(s: &str) -> Result<String, ExpressionError>
+    {
+        Expression::from_str(s)?.evaluate(&converter().exchange_rates(), Locale::English)
+    }
+
+    #[test]
+    fn test_mixed_lengths()
+    {
+        assert_eq!(evaluate("5 ft + 30 cm in m").unwrap(), "1.82 Metre(s) [m]");
+        assert_eq!(
+            evaluate("5 ft 10 in to cm").unwrap(),
+            "177.80 Centimetre(s) [cm]"
+        );
+        assert_eq!(evaluate("5 in + 3 in").unwrap(), "8.00 Inch(es) [in]");
+        assert_eq!(
+            evaluate("5 in + 3 in in cm").unwrap(),
+            "20.32 Centimetre(s) [cm]"
+        );
+    }
+
+    #[test]
+    fn test_scaled_volume()
+    {
+        assert_eq!(evaluate("3 * 250 ml to cups").unwrap(), "3.17 Cup(s) [cup]");
+        assert_eq!(evaluate("(1 L - 250 ml) / 3").unwrap(), "0.25 Litre(s) [L]");
+    }
+
+    #[test]
+    fn test_currency()
+    {
+        assert_eq!(
+            evaluate("$20 + €15 to GBP").unwrap(),
+            "30.01 Brittish Pound(s) [GBP]"
+        );
+        assert!(Expression::from_str("$20 + €15 to GBP")
+            .unwrap()
+            .uses_currency());
+    }
+
+    #[test]
+    fn test_incompatible_units()
+    {
+        assert_eq!(
+            evaluate("5 ft + 2 L").unwrap_err().to_string(),
+            "Can't add Feet [ft] (length) and Litre(s) [L] (volume)"
+        );
+        assert!(matches!(
+            evaluate("5 kWh in psi"),
+            Err(ExpressionError::IncompatibleUnits { .. })
+        ));
+        assert!(matches!(
+            evaluate("5 furlongs"),
+            Err(ExpressionError::UnknownUnit(_))
+        ));
+        assert!(matches!(
+            evaluate("1 m / 0"),
+            Err(ExpressionError::DivisionByZero)
+        ));
+    }
+}
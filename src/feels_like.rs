@@ -0,0 +1,417 @@
+use crate::{
+    i18n::Locale,
+    quantity::{format_value, split_quantity},
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+    temperature::{ParseTempError, Temperature, TemperatureUnit},
+};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `feels-like` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "feels-like",
+    "Calculate the heat index, dew point, humidex, or wind chill.",
+)
+.details("Calculate the heat index, dew point, and humidex from the humidity, or the wind chill from the wind speed")
+.options(&[
+    OptionSpec::string("temperature", "The air temperature (e.g. '86F', '30C')."),
+    OptionSpec::string("humidity", "Relative humidity (e.g. '70%'), for the heat index, dew point, and humidex.").optional(),
+    OptionSpec::string("wind", "Wind speed (e.g. '20 km/h', '12 mph', '5 m/s', '10 kn'), for the wind chill.").optional(),
+    OptionSpec::string("target", "The unit to show results in (e.g 'F', 'C'). Defaults to the temperature's unit.").optional(),
+]);
+
+pub fn run(
+    temperature: String,
+    humidity: Option<String>,
+    wind: Option<String>,
+    target: Option<String>,
+) -> String
+{
+    convert(temperature, humidity, wind, target, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, with the estimates'
+/// warnings kept apart from the estimates. Written in `locale`.
+pub fn convert(
+    temperature: String,
+    humidity: Option<String>,
+    wind: Option<String>,
+    target: Option<String>,
+    locale: Locale,
+) -> Reply
+{
+    let (input, estimates) = match calculate(temperature, humidity, wind, target, locale)
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    Reply::Conversion(Conversion {
+        input,
+        output: estimates
+            .iter()
+            .map(|(name, estimate)| {
+                format!(
+                    "{}: {}",
+                    locale.text(name, &[]),
+                    estimate.temperature.text(locale)
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        warnings: estimates
+            .iter()
+            .flat_map(|(_, estimate)| estimate.warnings.iter().map(|x| locale.text(x, &[])))
+            .collect(),
+        ..Default::default()
+    })
+}
+
+/// Estimates, each with the catalogue key for its name.
+type Estimates = Vec<(&'static str, Estimate)>;
+
+/// A description of the inputs in `locale`, and each estimate they allow.
+fn calculate(
+    temperature: String,
+    humidity: Option<String>,
+    wind: Option<String>,
+    target: Option<String>,
+    locale: Locale,
+) -> Result<(String, Estimates), FeelsLikeError>
+{
+    let temperature = Temperature::from_str(&temperature)?;
+    let target = match target
+    {
+        Some(x) => TemperatureUnit::from_str(&x)?,
+        None => temperature.unit(),
+    };
+    let mut input = temperature.text(locale);
+
+    let mut estimates = Vec::new();
+    if let Some(humidity) = humidity
+    {
+        let humidity = parse_humidity(&humidity)?;
+        input.push_str(&locale.text(
+            "feels-like.humidity",
+            &[("humidity", &humidity.to_string())],
+        ));
+        estimates.push(("feels-like.heat-index", heat_index(temperature, humidity)?));
+        estimates.push(("feels-like.dew-point", dew_point(temperature, humidity)?));
+        estimates.push(("feels-like.humidex", humidex(temperature, humidity)?));
+    }
+    if let Some(wind) = wind
+    {
+        let wind = parse_wind_speed(&wind)?;
+        input.push_str(&locale.text("feels-like.wind", &[("wind", &format_value(wind))]));
+        estimates.push(("feels-like.wind-chill", wind_chill(temperature, wind)?));
+    }
+
+    if estimates.is_empty()
+    {
+        return Err(FeelsLikeError::NothingToCalculate);
+    }
+    Ok((
+        input,
+        estimates
+            .into_iter()
+            .map(|(name, estimate)| (name, estimate.to(target)))
+            .collect(),
+    ))
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum FeelsLikeError
+{
+    InvalidHumidity(String),
+    InvalidWindSpeed(String),
+    NothingToCalculate,
+    Temperature(#[from] ParseTempError),
+}
+
+impl FeelsLikeError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::InvalidHumidity(humidity) => locale.text(
+                "error.feels-like.invalid-humidity",
+                &[("humidity", humidity)],
+            ),
+            Self::InvalidWindSpeed(wind) =>
+            {
+                locale.text("error.feels-like.invalid-wind-speed", &[("wind", wind)])
+            }
+            Self::NothingToCalculate => locale.text("error.feels-like.nothing-to-calculate", &[]),
+            Self::Temperature(e) => e.text(locale),
+        }
+    }
+}
+
+/// A calculated temperature, along with warnings for when it was calculated
+/// outside the range its formula is meant for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Estimate
+{
+    pub temperature: Temperature,
+
+    /// Catalogue keys for the warnings
+    pub warnings: Vec<&'static str>,
+}
+
+impl Estimate
+{
+    /// An estimate of `celsius`, which fails for absurd input that takes a
+    /// formula below absolute zero.
+    fn new(celsius: f64) -> Result<Self, FeelsLikeError>
+    {
+        Ok(Self {
+            temperature: Temperature::new(celsius, TemperatureUnit::Celsius)?,
+            warnings: Vec::new(),
+        })
+    }
+
+    fn warn_if(mut self, condition: bool, warning: &'static str) -> Self
+    {
+        if condition
+        {
+            self.warnings.push(warning);
+        }
+        self
+    }
+
+    /// Show the estimate in `unit`.
+    pub fn to(mut self, unit: TemperatureUnit) -> Self
+    {
+        self.temperature.as_unit(unit);
+        self
+    }
+
+    /// The estimate and its warnings as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        self.warnings
+            .iter()
+            .fold(self.temperature.text(locale), |text, warning| {
+                text + &locale.text(
+                    "feels-like.warning",
+                    &[("warning", &locale.text(warning, &[]))],
+                )
+            })
+    }
+}
+
+impl fmt::Display for Estimate
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+/// Parse a relative humidity percentage (e.g. '65%', '65').
+pub fn parse_humidity(s: &str) -> Result<f64, FeelsLikeError>
+{
+    match s.trim().trim_end_matches('%').trim().parse::<f64>()
+    {
+        Ok(x) if (0.0..=100.0).contains(&x) => Ok(x),
+        _ => Err(FeelsLikeError::InvalidHumidity(s.trim().to_string())),
+    }
+}
+
+/// Parse a wind speed (e.g. '20 km/h', '12 mph') into kilometres per hour.
+pub fn parse_wind_speed(s: &str) -> Result<f64, FeelsLikeError>
+{
+    let invalid = || FeelsLikeError::InvalidWindSpeed(s.trim().to_string());
+    let (value, unit) = split_quantity(s).map_err(|_| invalid())?;
+    let factor = match &*unit.to_lowercase()
+    {
+        "km/h" | "kmh" | "kph" | "" => 1.0,
+        "mph" | "mi/h" => 1.609344,
+        "m/s" | "mps" => 3.6,
+        "kn" | "kt" | "kts" | "knot" | "knots" => 1.852,
+        _ => return Err(invalid()),
+    };
+
+    if value < 0.0
+    {
+        return Err(invalid());
+    }
+    Ok(value * factor)
+}
+
+/// The NWS heat index, from the Rothfusz regression with Steadman's formula
+/// for mild conditions.
+pub fn heat_index(temperature: Temperature, humidity: f64) -> Result<Estimate, FeelsLikeError>
+{
+    let t = temperature.value_in(TemperatureUnit::Fahrenheit);
+    let rh = humidity;
+
+    let mut hi = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    if (hi + t) / 2.0 >= 80.0
+    {
+        hi = -42.379 + 2.04901523 * t + 10.14333127 * rh
+            - 0.22475541 * t * rh
+            - 0.00683783 * t * t
+            - 0.05481717 * rh * rh
+            + 0.00122874 * t * t * rh
+            + 0.00085282 * t * rh * rh
+            - 0.00000199 * t * t * rh * rh;
+
+        if rh < 13.0 && (80.0..=112.0).contains(&t)
+        {
+            hi -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+        }
+        else if rh > 85.0 && (80.0..=87.0).contains(&t)
+        {
+            hi += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+        }
+    }
+
+    Ok(Estimate::new((hi - 32.0) * 5.0 / 9.0)?
+        .warn_if(t < 80.0, "feels-like.heat-index-temperature")
+        .warn_if(rh < 40.0, "feels-like.heat-index-humidity"))
+}
+
+/// The North American wind chill index, for a wind speed in km/h.
+pub fn wind_chill(temperature: Temperature, wind_speed: f64) -> Result<Estimate, FeelsLikeError>
+{
+    let t = temperature.value_in(TemperatureUnit::Celsius);
+    let v = wind_speed.powf(0.16);
+
+    Ok(
+        Estimate::new(13.12 + 0.6215 * t - 11.37 * v + 0.3965 * t * v)?
+            .warn_if(t > 10.0, "feels-like.wind-chill-temperature")
+            .warn_if(wind_speed < 4.8, "feels-like.wind-chill-speed"),
+    )
+}
+
+/// The dew point, from the Magnus formula.
+pub fn dew_point(temperature: Temperature, humidity: f64) -> Result<Estimate, FeelsLikeError>
+{
+    const B: f64 = 17.62;
+    const C: f64 = 243.12;
+    let t = temperature.value_in(TemperatureUnit::Celsius);
+    // Zero humidity has no dew point, so use the smallest humidity instead
+    let gamma = (humidity.max(f64::MIN_POSITIVE) / 100.0).ln() + B * t / (C + t);
+
+    Ok(Estimate::new(C * gamma / (B - gamma))?
+        .warn_if(
+            !(-45.0..=60.0).contains(&t),
+            "feels-like.dew-point-temperature",
+        )
+        .warn_if(humidity < 1.0, "feels-like.dew-point-humidity"))
+}
+
+/// The Canadian humidex.
+pub fn humidex(temperature: Temperature, humidity: f64) -> Result<Estimate, FeelsLikeError>
+{
+    let t = temperature.value_in(TemperatureUnit::Celsius);
+    let dew_point = dew_point(temperature, humidity)?
+        .temperature
+        .value_in(TemperatureUnit::Kelvin);
+    let e = 6.11 * (5417.7530 * (1.0 / 273.16 - 1.0 / dew_point)).exp();
+
+    Ok(Estimate::new(t + 0.5555 * (e - 10.0))?.warn_if(t < 20.0, "feels-like.humidex-temperature"))
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn temp(s: &str) -> Temperature { Temperature::from_str(s).unwrap() }
+
+    #[test]
+    fn test_heat_index()
+    {
+        let estimate = heat_index(temp("90F"), 60.0)
+            .unwrap()
+            .to(TemperatureUnit::Fahrenheit);
+        assert_eq!(estimate.to_string(), "99.68 Fahrenheit");
+
+        let estimate = heat_index(temp("20C"), 60.0).unwrap();
+        assert_eq!(estimate.warnings.len(), 1);
+    }
+
+    #[test]
+    fn test_wind_chill()
+    {
+        let estimate = wind_chill(temp("-10C"), parse_wind_speed("30 km/h").unwrap()).unwrap();
+        assert_eq!(estimate.to_string(), "-19.52 Celsius");
+        assert!(estimate.warnings.is_empty());
+
+        let estimate = wind_chill(temp("20C"), parse_wind_speed("2 mph").unwrap()).unwrap();
+        assert_eq!(estimate.warnings.len(), 2);
+
+        // Absurd input is an error, not absolute zero
+        assert!(matches!(
+            wind_chill(temp("0K"), 100.0),
+            Err(FeelsLikeError::Temperature(
+                ParseTempError::BelowAbsoluteZero(..)
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_dew_point_and_humidex()
+    {
+        assert_eq!(
+            dew_point(temp("30C"), 70.0).unwrap().to_string(),
+            "23.93 Celsius"
+        );
+        assert_eq!(
+            humidex(temp("30C"), 70.0).unwrap().to_string(),
+            "41.20 Celsius"
+        );
+    }
+
+    #[test]
+    fn test_run()
+    {
+        assert_eq!(
+            run(
+                "86F".to_string(),
+                Some("70%".to_string()),
+                None,
+                Some("C".to_string())
+            ),
+            "86.00 Fahrenheit, 70% humidity -> Heat index: 35.04 Celsius\nDew point: 23.93 \
+             Celsius\nHumidex: 41.20 Celsius"
+        );
+        assert!(matches!(
+            convert(
+                "86F".to_string(),
+                Some("170%".to_string()),
+                None,
+                None,
+                Locale::English
+            ),
+            Reply::Error(_)
+        ));
+        assert!(matches!(
+            convert("86F".to_string(), None, None, None, Locale::English),
+            Reply::Error(_)
+        ));
+
+        // Warnings are kept apart from the estimates
+        match convert(
+            "50F".to_string(),
+            Some("20%".to_string()),
+            None,
+            None,
+            Locale::English,
+        )
+        {
+            Reply::Conversion(conversion) =>
+            {
+                assert!(!conversion.output.contains("warning"));
+                assert!(conversion.warnings[0].starts_with("the heat index"));
+            }
+            x => panic!("expected a conversion, got {x:?}"),
+        }
+    }
+}
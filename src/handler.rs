@@ -0,0 +1,804 @@
+use crate::{
+    area, countdown, currency,
+    currency::{CurrencyConverter, CurrencyType},
+    data, date, duration, energy, expression, feels_like,
+    i18n::Locale,
+    power,
+    preferences::{self, MemoryStore, Owner, PreferenceStore, Preferences, PreferencesError},
+    pressure,
+    reply::Reply,
+    scan, temperature, time, timestamp, timezone, COMMAND, MESSAGE_COMMAND, TYPED_COMMAND,
+};
+use serenity::{
+    builder::{CreateAutocompleteResponse, CreateInteractionResponse},
+    model::{
+        id::{GuildId, UserId},
+        prelude::interaction::{
+            application_command::{
+                ApplicationCommandInteraction, CommandDataOption, CommandDataOptionValue,
+            },
+            autocomplete::AutocompleteInteraction,
+            InteractionResponseType,
+        },
+    },
+};
+use std::{
+    collections::HashMap,
+    sync::{Mutex, PoisonError},
+};
+
+/// Answer a `/conversions` or `/convert` interaction, routing its subcommand
+/// to the right module, or a "Convert units" interaction on a message.
+pub fn handle(interaction: &ApplicationCommandInteraction, state: &ConversionsState) -> Response
+{
+    let mut caller = Caller {
+        user: interaction.user.id,
+        guild: interaction.guild_id,
+        manages_guild: interaction
+            .member
+            .as_ref()
+            .and_then(|member| member.permissions)
+            .is_some_and(|permissions| permissions.manage_guild()),
+        locale: Locale::from_tag(&interaction.locale),
+    };
+    // A locale they've chosen wins over their Discord client's
+    if let Some(tag) = caller.preferences(state).locale
+    {
+        caller.locale = Locale::from_tag(&tag);
+    }
+    let error = |key| Reply::Error(caller.locale.text(key, &[]));
+
+    let reply = match interaction.data.options.first()
+    {
+        _ if interaction.data.name == MESSAGE_COMMAND =>
+        {
+            let message = interaction
+                .data
+                .target_id
+                .and_then(|id| interaction.data.resolved.messages.get(&id.to_message_id()));
+            match message
+            {
+                Some(message) => convert_message(message.content.clone(), &caller, state),
+                None => error("error.no-message"),
+            }
+        }
+        Some(subcommand) =>
+        {
+            let arguments = Arguments::from_options(&subcommand.options, caller.locale);
+            if interaction.data.name == TYPED_COMMAND.name
+            {
+                dispatch_typed(&subcommand.name, &arguments, &caller, state)
+            }
+            else
+            {
+                dispatch(&subcommand.name, &arguments, &caller, state)
+            }
+        }
+        None => error("error.no-subcommand"),
+    };
+
+    Response {
+        reply,
+        // Direct messages get plain text
+        plain: interaction.guild_id.is_none(),
+        locale: caller.locale,
+    }
+}
+
+/// Suggest choices for the option being typed in an autocomplete interaction.
+pub fn autocomplete<'a>(
+    interaction: &AutocompleteInteraction,
+    response: &'a mut CreateAutocompleteResponse,
+) -> &'a mut CreateAutocompleteResponse
+{
+    let focused =
+        interaction.data.options.first().and_then(|subcommand| {
+            Some((subcommand, subcommand.options.iter().find(|x| x.focused)?))
+        });
+    if let Some((subcommand, option)) = focused
+    {
+        let partial = option
+            .value
+            .as_ref()
+            .and_then(|x| x.as_str())
+            .unwrap_or_default();
+        for (name, value) in suggest(
+            &interaction.data.name,
+            &subcommand.name,
+            &option.name,
+            partial,
+        )
+        {
+            response.add_string_choice(name, value);
+        }
+    }
+    response
+}
+
+/// The choices for an option that match what's been typed so far.
+fn suggest(command: &str, subcommand: &str, option: &str, partial: &str) -> Vec<(String, String)>
+{
+    [COMMAND, TYPED_COMMAND]
+        .iter()
+        .find(|spec| spec.name == command)
+        .and_then(|spec| spec.subcommand(subcommand))
+        .and_then(|spec| spec.option(option))
+        .map(|spec| spec.suggest(partial))
+        .unwrap_or_default()
+}
+
+/// State shared between conversions, kept by the bot for as long as it runs.
+#[derive(Debug)]
+pub struct ConversionsState
+{
+    /// The currency converter, which caches exchange rates between commands
+    converter: Mutex<CurrencyConverter>,
+
+    /// Where users' and guilds' preferences are kept
+    preferences: Mutex<Box<dyn PreferenceStore>>,
+}
+
+impl ConversionsState
+{
+    /// State with preferences kept in memory. Use
+    /// [`ConversionsState::with_preferences`] to keep them elsewhere.
+    pub fn new(converter: CurrencyConverter) -> Self
+    {
+        Self {
+            converter: Mutex::new(converter),
+            preferences: Mutex::new(Box::<MemoryStore>::default()),
+        }
+    }
+
+    /// Keep preferences in `store` (e.g. a [`JsonStore`](crate::JsonStore)).
+    pub fn with_preferences(mut self, store: impl PreferenceStore + 'static) -> Self
+    {
+        self.preferences = Mutex::new(Box::new(store));
+        self
+    }
+
+    /// The preferences `owner` has set, without falling back to anyone
+    /// else's.
+    pub fn preferences(&self, owner: Owner) -> Result<Preferences, PreferencesError>
+    {
+        self.with_store(|store| store.get(owner))
+    }
+
+    /// A user's preferences, falling back to their guild's defaults.
+    pub fn preferences_for(
+        &self,
+        user: UserId,
+        guild: Option<GuildId>,
+    ) -> Result<Preferences, PreferencesError>
+    {
+        let preferences = self.preferences(Owner::User(user))?;
+        match guild
+        {
+            Some(guild) => Ok(preferences.or(self.preferences(Owner::Guild(guild))?)),
+            None => Ok(preferences),
+        }
+    }
+
+    fn with_store<T>(&self, f: impl FnOnce(&mut dyn PreferenceStore) -> T) -> T
+    {
+        let mut store = self
+            .preferences
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        f(store.as_mut())
+    }
+
+    /// Run something that needs the currency converter, keeping the converter
+    /// it hands back (e.g. with refreshed exchange rates).
+    pub(crate) fn with_converter<T>(
+        &self,
+        f: impl FnOnce(CurrencyConverter) -> (T, CurrencyConverter),
+    ) -> T
+    {
+        // A panic elsewhere can't leave the converter half-updated, so it's
+        // fine to keep using it
+        let mut converter = self
+            .converter
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (reply, updated) = f(converter.clone());
+        *converter = updated;
+        reply
+    }
+}
+
+/// A reply to a conversions command, ready to send.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response
+{
+    pub reply: Reply,
+
+    /// Whether to send the reply as plain text instead of an embed
+    pub plain: bool,
+
+    /// The language to label the reply in
+    pub locale: Locale,
+}
+
+impl Response
+{
+    /// Fill in an interaction response with the reply.
+    pub fn create<'a, 'b>(
+        &self,
+        response: &'b mut CreateInteractionResponse<'a>,
+    ) -> &'b mut CreateInteractionResponse<'a>
+    {
+        response
+            .kind(InteractionResponseType::ChannelMessageWithSource)
+            .interaction_response_data(|message| {
+                if self.plain
+                {
+                    message.content(self.reply.text(self.locale))
+                }
+                else
+                {
+                    message.embed(|embed| self.reply.embed(embed, self.locale))
+                }
+            })
+    }
+}
+
+/// The values given for a subcommand's options, by name.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct Arguments
+{
+    values: HashMap<String, String>,
+
+    /// The language to explain missing or invalid options in
+    locale: Locale,
+}
+
+impl Arguments
+{
+    fn from_options(options: &[CommandDataOption], locale: Locale) -> Self
+    {
+        Self {
+            values: options
+                .iter()
+                .filter_map(|option| {
+                    let value = match option.resolved.as_ref()?
+                    {
+                        CommandDataOptionValue::String(x) => x.clone(),
+                        CommandDataOptionValue::Boolean(x) => x.to_string(),
+                        CommandDataOptionValue::Integer(x) => x.to_string(),
+                        CommandDataOptionValue::Number(x) => x.to_string(),
+                        _ => return None,
+                    };
+                    Some((option.name.clone(), value))
+                })
+                .collect(),
+            locale,
+        }
+    }
+
+    fn optional(&self, name: &str) -> Option<String> { self.values.get(name).cloned() }
+
+    fn required(&self, name: &str) -> Result<String, String>
+    {
+        self.optional(name)
+            .ok_or_else(|| self.locale.text("error.missing-option", &[("name", name)]))
+    }
+
+    fn number(&self, name: &str) -> Result<f64, String>
+    {
+        self.required(name)?
+            .parse()
+            .map_err(|_| self.locale.text("error.not-a-number", &[("name", name)]))
+    }
+
+    fn flag(&self, name: &str) -> bool { self.values.get(name).is_some_and(|x| x == "true") }
+}
+
+/// Who ran a command, for looking up and changing their preferences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Caller
+{
+    user: UserId,
+
+    /// The guild the command was run in, if it wasn't a direct message
+    guild: Option<GuildId>,
+
+    /// Whether they can change the guild's defaults
+    manages_guild: bool,
+
+    /// The language to reply in
+    locale: Locale,
+}
+
+impl Caller
+{
+    /// Their preferences, falling back to their guild's defaults. If they
+    /// can't be read, none are used, so only `settings` fails when the store
+    /// is broken.
+    fn preferences(&self, state: &ConversionsState) -> Preferences
+    {
+        state
+            .preferences_for(self.user, self.guild)
+            .unwrap_or_default()
+    }
+
+    /// Whose preferences to change: their guild's defaults if `guild` is set,
+    /// otherwise their own.
+    fn owner(&self, guild: bool) -> Result<Owner, String>
+    {
+        if !guild
+        {
+            return Ok(Owner::User(self.user));
+        }
+        match self.guild
+        {
+            Some(_) if !self.manages_guild =>
+            {
+                Err(self.locale.text("error.missing-permission", &[]))
+            }
+            Some(id) => Ok(Owner::Guild(id)),
+            None => Err(self.locale.text("error.not-in-guild", &[])),
+        }
+    }
+}
+
+/// An option that was given, or else the caller's preference for it.
+fn or_preferred(given: Option<String>, preferred: Option<impl ToString>) -> Option<String>
+{
+    given.or_else(|| preferred.map(|x| x.to_string()))
+}
+
+/// Run `subcommand` with its arguments.
+fn dispatch(
+    subcommand: &str,
+    arguments: &Arguments,
+    caller: &Caller,
+    state: &ConversionsState,
+) -> Reply
+{
+    let reply = || -> Result<Reply, String> {
+        let preferences = caller.preferences(state);
+        let locale = caller.locale;
+        Ok(match subcommand
+        {
+            "temperature" => temperature::convert(
+                arguments.required("value")?,
+                or_preferred(arguments.optional("target"), preferences.temperature),
+                arguments.flag("difference"),
+                locale,
+            ),
+            "currency" =>
+            {
+                let input = arguments.required("input")?;
+                let target = or_preferred(
+                    arguments.optional("target"),
+                    preferences.currency.map(CurrencyType::code),
+                );
+                state
+                    .with_converter(|converter| currency::convert(converter, input, target, locale))
+            }
+            "data" => data::convert(
+                arguments.required("value")?,
+                arguments.required("target")?,
+                locale,
+            ),
+            "transfer" => data::convert_transfer(
+                arguments.required("size")?,
+                arguments.required("rate")?,
+                locale,
+            ),
+            "area" => area::convert(
+                arguments.required("value")?,
+                arguments.required("target")?,
+                locale,
+            ),
+            "energy" => energy::convert(
+                arguments.required("value")?,
+                arguments.required("target")?,
+                locale,
+            ),
+            "power" => power::convert(
+                arguments.required("value")?,
+                arguments.required("target")?,
+                locale,
+            ),
+            "pressure" => pressure::convert(
+                arguments.required("value")?,
+                arguments.required("target")?,
+                locale,
+            ),
+            "calculate" =>
+            {
+                let input = arguments.required("expression")?;
+                state.with_converter(|converter| expression::convert(converter, input, locale))
+            }
+            "feels-like" => feels_like::convert(
+                arguments.required("temperature")?,
+                arguments.optional("humidity"),
+                arguments.optional("wind"),
+                arguments.optional("target"),
+                locale,
+            ),
+            "hours" => time::convert(
+                arguments.required("time")?,
+                arguments.flag("lenient"),
+                or_preferred(arguments.optional("target"), preferences.notation),
+                locale,
+            ),
+            "timezone" => timezone::convert(
+                arguments.required("time")?,
+                or_preferred(arguments.optional("target"), preferences.zone)
+                    .ok_or_else(|| locale.text("error.no-zone", &[]))?,
+                locale,
+            ),
+            "date" => date::convert(
+                arguments.required("value")?,
+                arguments.optional("target"),
+                arguments.optional("order"),
+                locale,
+            ),
+            "duration" => duration::convert(
+                arguments.required("input")?,
+                arguments.optional("target"),
+                locale,
+            ),
+            "timestamp" => timestamp::convert(
+                arguments.required("time")?,
+                arguments.optional("date"),
+                or_preferred(arguments.optional("zone"), preferences.zone),
+                locale,
+            ),
+            "countdown" => countdown::convert(
+                arguments.required("target")?,
+                or_preferred(arguments.optional("zone"), preferences.zone),
+                locale,
+            ),
+            "settings" =>
+            {
+                let changes = Preferences::parse(
+                    arguments.optional("temperature"),
+                    arguments.optional("currency"),
+                    arguments.optional("notation"),
+                    arguments.optional("zone"),
+                    arguments.optional("locale"),
+                )
+                .map_err(|e| e.text(locale))?;
+                let owner = caller.owner(arguments.flag("server"))?;
+                let reset = arguments.flag("reset");
+                state.with_store(|store| preferences::reply(store, owner, changes, reset, locale))
+            }
+            _ => return Err(locale.text("error.unknown-subcommand", &[("name", subcommand)])),
+        })
+    };
+
+    reply().unwrap_or_else(Reply::Error)
+}
+
+/// Convert everything found in a message, to the caller's preferred units.
+fn convert_message(content: String, caller: &Caller, state: &ConversionsState) -> Reply
+{
+    let preferences = caller.preferences(state);
+    state.with_converter(|converter| scan::convert(converter, content, &preferences, caller.locale))
+}
+
+/// Run `subcommand` of the typed command layout with its arguments.
+fn dispatch_typed(
+    subcommand: &str,
+    arguments: &Arguments,
+    caller: &Caller,
+    state: &ConversionsState,
+) -> Reply
+{
+    let reply = || -> Result<Reply, String> {
+        let preferences = caller.preferences(state);
+        let locale = caller.locale;
+        let (value, unit) = (arguments.number("value")?, arguments.required("unit")?);
+        Ok(match subcommand
+        {
+            "temperature" => temperature::convert_value(
+                value,
+                unit,
+                or_preferred(arguments.optional("target"), preferences.temperature),
+                arguments.flag("difference"),
+                locale,
+            ),
+            "currency" =>
+            {
+                let target = or_preferred(
+                    arguments.optional("target"),
+                    preferences.currency.map(CurrencyType::code),
+                );
+                state.with_converter(|converter| {
+                    currency::convert_value(converter, value, unit, target, locale)
+                })
+            }
+            "data" => data::convert_value(value, unit, arguments.required("target")?, locale),
+            "area" => area::convert_value(value, unit, arguments.required("target")?, locale),
+            "energy" => energy::convert_value(value, unit, arguments.required("target")?, locale),
+            "power" => power::convert_value(value, unit, arguments.required("target")?, locale),
+            "pressure" =>
+            {
+                pressure::convert_value(value, unit, arguments.required("target")?, locale)
+            }
+            _ => return Err(locale.text("error.unknown-subcommand", &[("name", subcommand)])),
+        })
+    };
+
+    reply().unwrap_or_else(Reply::Error)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use serenity::{builder::CreateEmbed, utils::Colour};
+    use std::io;
+
+    fn caller() -> Caller
+    {
+        Caller {
+            user: UserId(1),
+            guild: Some(GuildId(2)),
+            manages_guild: false,
+            locale: Locale::English,
+        }
+    }
+
+    fn arguments(pairs: &[(&str, &str)]) -> Arguments
+    {
+        Arguments {
+            values: pairs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect(),
+            locale: Locale::English,
+        }
+    }
+
+    #[test]
+    fn test_dispatch()
+    {
+        let state = ConversionsState::new(currency::tests::converter());
+        let caller = caller();
+        assert_eq!(
+            dispatch("hours", &arguments(&[("time", "14:30")]), &caller, &state).to_string(),
+            "14:30:00 -> 02:30:00 PM"
+        );
+        assert_eq!(
+            dispatch(
+                "temperature",
+                &arguments(&[("value", "10C"), ("target", "F"), ("difference", "true")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            temperature::run("10C".to_string(), "F".to_string(), true)
+        );
+        assert_eq!(
+            dispatch(
+                "calculate",
+                &arguments(&[("expression", "2 km + 500 m")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            expression::run(currency::tests::converter(), "2 km + 500 m".to_string()).0
+        );
+        match dispatch(
+            "currency",
+            &arguments(&[("input", "$45"), ("target", "eur")]),
+            &caller,
+            &state,
+        )
+        {
+            Reply::Conversion(conversion) =>
+            {
+                assert_eq!(conversion.output, "41.94 Euro(s) [EUR]");
+                assert_eq!(conversion.rate, Some(0.932001));
+                assert_eq!(conversion.source, Some("currencyapi.com"));
+            }
+            reply => panic!("Expected a conversion, got {reply:?}"),
+        }
+        assert_eq!(
+            dispatch("hours", &arguments(&[]), &caller, &state).to_string(),
+            "Error: Missing option 'time'"
+        );
+        assert_eq!(
+            dispatch("weather", &arguments(&[]), &caller, &state).to_string(),
+            "Error: Unknown subcommand 'weather'"
+        );
+
+        // Failures from the subcommands themselves are shown as errors too
+        for (subcommand, pairs) in [
+            ("hours", &[("time", "25:00")][..]),
+            ("feels-like", &[("temperature", "86F")]),
+            ("date", &[("value", "31/31/2023")]),
+            ("duration", &[("input", "5 parsecs")]),
+            ("transfer", &[("size", "1 GB"), ("rate", "0 MB/s")]),
+            ("calculate", &[("expression", "5 m + 3 kg")]),
+        ]
+        {
+            let reply = dispatch(subcommand, &arguments(pairs), &caller, &state);
+            assert!(
+                matches!(reply, Reply::Error(_)),
+                "{subcommand} gave {reply:?}"
+            );
+            let mut embed = CreateEmbed::default();
+            reply.embed(&mut embed, caller.locale);
+            assert_eq!(embed.0["color"], Colour::RED.0);
+        }
+    }
+
+    #[test]
+    fn test_preferences()
+    {
+        let state = ConversionsState::new(currency::tests::converter());
+        let caller = caller();
+        let settings = |pairs: &[(&str, &str)], caller: &Caller| {
+            dispatch("settings", &arguments(pairs), caller, &state).to_string()
+        };
+        assert!(
+            settings(&[("temperature", "kelvin"), ("zone", "Tokyo")], &caller)
+                .starts_with("Your preferences:\nTemperature: Kelvin\n")
+        );
+        assert_eq!(
+            dispatch(
+                "temperature",
+                &arguments(&[("value", "0C")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            "0.00 Celsius -> 273.15 Kelvin"
+        );
+        assert_eq!(
+            settings(&[("notation", "24h"), ("server", "true")], &caller),
+            "Error: Changing the server's defaults needs the Manage Server permission"
+        );
+
+        // The guild's defaults apply to anything the user hasn't set
+        let admin = Caller {
+            user: UserId(3),
+            manages_guild: true,
+            ..caller
+        };
+        settings(&[("currency", "gbp"), ("server", "true")], &admin);
+        match dispatch("currency", &arguments(&[("input", "$45")]), &caller, &state)
+        {
+            Reply::Conversion(conversion) =>
+            {
+                assert_eq!(conversion.output, "37.42 Brittish Pound(s) [GBP]")
+            }
+            reply => panic!("Expected a conversion, got {reply:?}"),
+        }
+        assert!(settings(&[("reset", "true")], &caller).contains("Temperature: not set"));
+        assert_eq!(
+            dispatch(
+                "timezone",
+                &arguments(&[("time", "3pm EST")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            "Error: Missing option 'target', and no preferred time zone is set"
+        );
+
+        // Replies are in the caller's language
+        let german = Caller {
+            locale: Locale::German,
+            ..caller
+        };
+        assert_eq!(
+            dispatch("settings", &arguments(&[("server", "true")]), &german, &state)
+                .text(german.locale),
+            "Fehler: Um die Serverstandards zu ändern, brauchst du die Berechtigung „Server verwalten“"
+        );
+        assert!(settings(&[], &german).starts_with("Deine Einstellungen:\nTemperatur: "));
+        assert_eq!(
+            dispatch("hours", &arguments(&[("time", "25:00")]), &german, &state)
+                .text(german.locale),
+            "Fehler: '25:00' hat ein ungültiges Format: Stunde 25 liegt außerhalb des Bereichs (0 \
+             bis 23). Beispiele: '12:20 PM' oder '17:00:08'."
+        );
+        assert_eq!(
+            dispatch(
+                "duration",
+                &arguments(&[("input", "from 09:15 to 17:40")]),
+                &german,
+                &state
+            )
+            .text(german.locale),
+            "09:15:00 bis 17:40:00 -> 8h 25m (8 Stunden und 25 Minuten)"
+        );
+    }
+
+    /// A store which can't be read or written.
+    #[derive(Debug)]
+    struct BrokenStore;
+
+    impl PreferenceStore for BrokenStore
+    {
+        fn get(&self, _: Owner) -> Result<Preferences, PreferencesError>
+        {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "denied").into())
+        }
+
+        fn set(&mut self, _: Owner, _: Preferences) -> Result<(), PreferencesError>
+        {
+            Err(io::Error::new(io::ErrorKind::PermissionDenied, "denied").into())
+        }
+    }
+
+    #[test]
+    fn test_broken_store()
+    {
+        let state =
+            ConversionsState::new(currency::tests::converter()).with_preferences(BrokenStore);
+        let caller = caller();
+
+        // Everything but the settings works without preferences
+        assert_eq!(
+            dispatch("hours", &arguments(&[("time", "14:30")]), &caller, &state).to_string(),
+            "14:30:00 -> 02:30:00 PM"
+        );
+        assert_eq!(
+            dispatch_typed(
+                "temperature",
+                &arguments(&[("value", "0"), ("unit", "C")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            "0.00 Celsius -> 32.00 Fahrenheit"
+        );
+        assert_eq!(
+            dispatch("settings", &arguments(&[]), &caller, &state).to_string(),
+            "Error: Couldn't read or write preferences: denied"
+        );
+    }
+
+    #[test]
+    fn test_message()
+    {
+        let state = ConversionsState::new(currency::tests::converter());
+        let caller = caller();
+        assert_eq!(
+            convert_message("Boil at 100°C".to_string(), &caller, &state).to_string(),
+            "100.00 Celsius -> 212.00 Fahrenheit"
+        );
+        assert!(matches!(
+            convert_message("Nothing to see".to_string(), &caller, &state),
+            Reply::Error(_)
+        ));
+    }
+
+    #[test]
+    fn test_typed()
+    {
+        let state = ConversionsState::new(currency::tests::converter());
+        let caller = caller();
+        assert_eq!(
+            dispatch_typed(
+                "energy",
+                &arguments(&[("value", "1"), ("unit", "kWh"), ("target", "BTU")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            "1.00 Kilowatt-hour(s) [kWh] -> 3412.14 British thermal unit(s) [BTU]"
+        );
+        assert_eq!(
+            dispatch_typed(
+                "energy",
+                &arguments(&[("value", "one"), ("unit", "kWh"), ("target", "BTU")]),
+                &caller,
+                &state
+            )
+            .to_string(),
+            "Error: Option 'value' must be a number"
+        );
+
+        assert_eq!(suggest("convert", "data", "unit", "gib").len(), 4);
+        assert!(suggest("conversions", "data", "value", "gib").is_empty());
+    }
+}
@@ -0,0 +1,1044 @@
+/// A language replies and command metadata can be shown in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale
+{
+    #[default]
+    English,
+    German,
+}
+
+impl Locale
+{
+    pub const ALL: [Self; 2] = [Self::English, Self::German];
+
+    /// The locale for a language tag (e.g. 'de', 'de-AT', 'en-GB'), or English
+    /// if there's no catalogue for its language.
+    pub fn from_tag(tag: &str) -> Self
+    {
+        let language = tag
+            .trim()
+            .split(['-', '_'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match &*language
+        {
+            "de" => Self::German,
+            _ => Self::English,
+        }
+    }
+
+    /// The code Discord uses for this locale.
+    pub fn discord_code(self) -> &'static str
+    {
+        match self
+        {
+            Self::English => "en-US",
+            Self::German => "de",
+        }
+    }
+
+    fn catalogue(self) -> &'static [(&'static str, &'static str)]
+    {
+        match self
+        {
+            Self::English => ENGLISH,
+            Self::German => GERMAN,
+        }
+    }
+
+    /// The text for `key` in this locale, if it has been translated.
+    pub fn translate(self, key: &str) -> Option<&'static str>
+    {
+        self.catalogue()
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, text)| *text)
+    }
+
+    /// The text for `key`, falling back to English, then to the key itself.
+    /// Placeholders (e.g. '{name}') are filled in from `arguments`.
+    pub fn text(self, key: &str, arguments: &[(&str, &str)]) -> String
+    {
+        let text = self
+            .translate(key)
+            .or_else(|| Self::English.translate(key))
+            .unwrap_or(key);
+        arguments
+            .iter()
+            .fold(text.to_string(), |text, (name, value)| {
+                text.replace(&format!("{{{name}}}"), value)
+            })
+    }
+}
+
+/// Names for things (e.g. units) in one language, as its tag (e.g. 'ru') and
+/// each name with what it means. Parsers accept them whatever the reader's
+/// locale, since messages can be written in any language.
+pub type Aliases<T> = (&'static str, &'static [(&'static str, T)]);
+
+/// Every alias in `registry`, in all of its languages.
+pub fn aliases<T: Copy>(registry: &'static [Aliases<T>])
+    -> impl Iterator<Item = (&'static str, T)>
+{
+    registry
+        .iter()
+        .flat_map(|(_, aliases)| aliases.iter().copied())
+}
+
+/// Messages in replies, from their labels to what each conversion writes (e.g.
+/// unit names and errors). Command metadata is written in English in the
+/// specs, so only needs translating.
+const ENGLISH: &[(&str, &str)] = &[
+    ("reply.input", "Input"),
+    ("reply.output", "Output"),
+    ("reply.units", "Units"),
+    ("reply.rate", "Rate"),
+    ("reply.rate-updated", "{rate} (updated {when})"),
+    ("reply.warnings", "Warnings"),
+    ("reply.warning", "(warning: {warning})"),
+    ("reply.error", "Error"),
+    ("reply.error-prefix", "Error: {error}"),
+    ("reply.source", "Data from {source}"),
+    ("error.missing-option", "Missing option '{name}'"),
+    ("error.not-a-number", "Option '{name}' must be a number"),
+    ("error.unknown-subcommand", "Unknown subcommand '{name}'"),
+    ("error.no-subcommand", "No subcommand given"),
+    ("error.no-message", "No message given"),
+    (
+        "error.no-zone",
+        "Missing option 'target', and no preferred time zone is set",
+    ),
+    (
+        "error.not-in-guild",
+        "Server defaults can only be changed in a server",
+    ),
+    (
+        "error.missing-permission",
+        "Changing the server's defaults needs the Manage Server permission",
+    ),
+    ("settings.user", "Your preferences"),
+    ("settings.guild", "This server's defaults"),
+    ("settings.temperature", "Temperature"),
+    ("settings.currency", "Currency"),
+    ("settings.notation", "Time notation"),
+    ("settings.zone", "Time zone"),
+    ("settings.locale", "Locale"),
+    ("settings.unset", "not set"),
+    // Temperatures
+    ("error.invalid-number", "Invalid number provided: {number}"),
+    ("error.temperature.invalid-unit", "Invalid unit provided"),
+    ("error.temperature.gas-mark-difference", "Gas marks can't be used for temperature differences"),
+    ("error.temperature.below-absolute-zero", "{value} is below absolute zero"),
+    ("error.temperature.not-finite", "{value} isn't a finite number"),
+    ("error.temperature.out-of-range", "{value} is out of range"),
+    ("temperature.gas-mark", "Gas mark {mark}"),
+    ("temperature.below-gas-marks", "Below gas mark 1/4"),
+    ("temperature.above-gas-marks", "Above gas mark 10"),
+    ("unit.kelvin", "Kelvin"),
+    ("unit.celsius", "Celsius"),
+    ("unit.fahrenheit", "Fahrenheit"),
+    ("unit.rankine", "Rankine"),
+    ("unit.reaumur", "Réaumur"),
+    ("unit.delisle", "Delisle"),
+    ("unit.newton", "Newton"),
+    ("unit.romer", "Rømer"),
+    ("unit.gasmark", "Gas mark"),
+    // Lengths
+    ("unit.mm", "Millimetre(s)"),
+    ("unit.cm", "Centimetre(s)"),
+    ("unit.m", "Metre(s)"),
+    ("unit.km", "Kilometre(s)"),
+    ("unit.in", "Inch(es)"),
+    ("unit.ft", "Feet"),
+    ("unit.yd", "Yard(s)"),
+    ("unit.mi", "Mile(s)"),
+    // Areas
+    ("unit.m²", "Square metre(s)"),
+    ("unit.km²", "Square kilometre(s)"),
+    ("unit.ft²", "Square feet"),
+    ("unit.yd²", "Square yard(s)"),
+    ("unit.ac", "Acre(s)"),
+    ("unit.ha", "Hectare(s)"),
+    // Energy
+    ("unit.J", "Joule(s)"),
+    ("unit.kJ", "Kilojoule(s)"),
+    ("unit.cal", "Calorie(s)"),
+    ("unit.kcal", "Kilocalorie(s)"),
+    ("unit.kWh", "Kilowatt-hour(s)"),
+    ("unit.BTU", "British thermal unit(s)"),
+    ("unit.eV", "Electronvolt(s)"),
+    // Power
+    ("unit.W", "Watt(s)"),
+    ("unit.kW", "Kilowatt(s)"),
+    ("unit.hp", "Mechanical horsepower"),
+    ("unit.PS", "Metric horsepower"),
+    // Pressure
+    ("unit.Pa", "Pascal(s)"),
+    ("unit.kPa", "Kilopascal(s)"),
+    ("unit.bar", "Bar"),
+    ("unit.psi", "Pound(s) per square inch"),
+    ("unit.atm", "Atmosphere(s)"),
+    ("unit.mmHg", "Millimetre(s) of mercury"),
+    ("unit.inHg", "Inch(es) of mercury"),
+    // Volumes
+    ("unit.mL", "Millilitre(s)"),
+    ("unit.L", "Litre(s)"),
+    ("unit.tsp", "Teaspoon(s)"),
+    ("unit.tbsp", "Tablespoon(s)"),
+    ("unit.fl oz", "Fluid ounce(s)"),
+    ("unit.cup", "Cup(s)"),
+    ("unit.pt", "Pint(s)"),
+    ("unit.qt", "Quart(s)"),
+    ("unit.gal", "Gallon(s)"),
+    // Durations
+    ("unit.ms", "Millisecond(s)"),
+    ("unit.s", "Second(s)"),
+    ("unit.min", "Minute(s)"),
+    ("unit.h", "Hour(s)"),
+    ("unit.d", "Day(s)"),
+    ("unit.wk", "Week(s)"),
+    // Data
+    ("error.invalid-unit", "Invalid unit provided: '{unit}'"),
+    ("error.data.unexpected-rate", "'{unit}' is a data rate, expected a size (e.g. 'MB', 'GiB')"),
+    ("error.data.expected-rate", "'{unit}' is a size, expected a data rate (e.g. 'Mbps', 'MB/s')"),
+    ("error.data.not-positive-rate", "The transfer rate must be greater than zero"),
+    ("error.data.too-long", "Transferring {size} would take longer than 30 million years"),
+    ("data.unit", "{name}(s) [{symbol}]"),
+    ("data.per-second", "{unit} per second"),
+    ("data.transfer", "{size} at {rate}"),
+    ("data.bits-iec", "1 {symbol} = {power} bits (IEC, binary)"),
+    ("data.bits-si", "1 {symbol} = {power} bits (SI, decimal)"),
+    ("data.bytes-iec", "1 {symbol} = {power} bytes (IEC, binary)"),
+    ("data.bytes-si", "1 {symbol} = {power} bytes (SI, decimal)"),
+    // Currencies
+    ("error.currency.invalid-currency", "Invalid currency provided: '{input}'"),
+    ("error.currency.invalid-target", "Invalid target currency"),
+    ("error.currency.request", "Couldn't request data from the currency API: {message}"),
+    ("error.currency.json", "Couldn't read the data returned by the currency API"),
+    ("currency.usd", "Dollar(s) [USD]"),
+    ("currency.eur", "Euro(s) [EUR]"),
+    ("currency.cad", "Canadian Dollar(s) [CAD]"),
+    ("currency.rub", "Ruble(s) [RUB]"),
+    ("currency.jpy", "Yen [JPY]"),
+    ("currency.aud", "Austriallian Dollar(s) [AUD]"),
+    ("currency.amd", "Dram [AMD]"),
+    ("currency.gbp", "Brittish Pound(s) [GBP]"),
+    ("currency.pkr", "Pakistani rupee(s) [PKR]"),
+    // Times
+    ("error.time.improper-form", "'{time}' is in improper form: {error}. Examples: '12:20 PM' or '17:00:08'."),
+    ("error.time.too-many-sections", "There were too many sections in the time provided"),
+    ("error.time.hour-out-of-range", "Hour {hour} is out of range (0 to 23)"),
+    ("error.time.minute-out-of-range", "Minute {minute} is out of range (0 to 59)"),
+    ("error.time.second-out-of-range", "Second {second} is out of range (0 to 59)"),
+    ("error.time.invalid-twelve-hour-hour", "Hour {hour} doesn't exist in 12 hour time (1 to 12)"),
+    ("error.time.unrecognised", "Couldn't understand '{time}' as a time"),
+    ("error.time.notation-out-of-range", "'{time}' is out of range for its notation"),
+    ("error.time.invalid-notation", "Invalid time notation: '{notation}' (expected '12h', '24h', 'decimal', 'beats', or 'decimal hours')"),
+    ("time.normalised", "Normalised from '{time}'{days}"),
+    ("time.next-day", ", the next day"),
+    ("time.days-later", ", {days} days later"),
+    // Time zones
+    ("error.timezone.unknown", "Unknown time zone: '{zone}' (e.g. 'Europe/London', 'EST', 'Tokyo', 'UTC+5:30')"),
+    ("error.timezone.missing", "No time zone was given (e.g. '3pm EST', '14:30 Europe/London')"),
+    ("error.timezone.skipped", "{time} doesn't exist in {zone} on {date}, the clocks skip it"),
+    ("day-offset.next", " (next day)"),
+    ("day-offset.previous", " (previous day)"),
+    ("day-offset.days", " ({days} days)"),
+    // Dates
+    ("error.date.unrecognised", "Couldn't understand '{date}' as a date (e.g. '2023-07-15', '15/07/2023', '15 July 2023', '1689379200')"),
+    ("error.date.out-of-range", "'{date}' isn't a real date"),
+    ("error.date.invalid-order", "Invalid date order: '{order}' (expected 'dmy' or 'mdy')"),
+    ("error.date.invalid-format", "Invalid date format: '{format}' (expected 'iso', 'rfc2822', 'us', 'eu', 'unix', or 'unix ms')"),
+    ("date.ambiguous", "Note: '{date}' was read as {order}. Set the order to change this"),
+    ("date.day-month-year", "day/month/year"),
+    ("date.month-day-year", "month/day/year"),
+    ("date.description", "{weekday}, ISO week {week}, day {day} of {days}"),
+    ("weekday.monday", "Monday"),
+    ("weekday.tuesday", "Tuesday"),
+    ("weekday.wednesday", "Wednesday"),
+    ("weekday.thursday", "Thursday"),
+    ("weekday.friday", "Friday"),
+    ("weekday.saturday", "Saturday"),
+    ("weekday.sunday", "Sunday"),
+    // Durations in words
+    ("error.duration.invalid", "Couldn't understand '{input}' as a duration (e.g. '1h30m', '90 minutes', 'PT1H30M')"),
+    ("error.duration.missing-unit", "'{number}' needs a unit (e.g. '{number} minutes')"),
+    ("error.duration.non-fixed-unit", "Years and months aren't a fixed length, so '{input}' can't be used"),
+    ("error.duration.invalid-calculation", "Couldn't calculate '{input}' (e.g. '14:30 + 2h45m', '17:40 - 09:15', '1h + 45m')"),
+    ("duration.range", "{start} to {end}"),
+    ("duration.negative", "minus {duration}"),
+    ("duration.week", "{amount} week"),
+    ("duration.weeks", "{amount} weeks"),
+    ("duration.day", "{amount} day"),
+    ("duration.days", "{amount} days"),
+    ("duration.hour", "{amount} hour"),
+    ("duration.hours", "{amount} hours"),
+    ("duration.minute", "{amount} minute"),
+    ("duration.minutes", "{amount} minutes"),
+    ("duration.second", "{amount} second"),
+    ("duration.seconds", "{amount} seconds"),
+    ("duration.millisecond", "{amount} millisecond"),
+    ("duration.milliseconds", "{amount} milliseconds"),
+    ("list.and", "{list} and {last}"),
+    // Countdowns
+    ("error.countdown.unrecognised", "Couldn't understand '{target}' (e.g. '6pm Friday UTC', 'Dec 25', '2023-12-25 18:00 EST')"),
+    ("countdown.date", "{weekday} {day} {month} {year}, {time}"),
+    ("countdown.past", "{when} was {words} ago ({duration})"),
+    ("countdown.future", "{when} is in {words} ({duration})"),
+    ("month.january", "January"),
+    ("month.february", "February"),
+    ("month.march", "March"),
+    ("month.april", "April"),
+    ("month.may", "May"),
+    ("month.june", "June"),
+    ("month.july", "July"),
+    ("month.august", "August"),
+    ("month.september", "September"),
+    ("month.october", "October"),
+    ("month.november", "November"),
+    ("month.december", "December"),
+    // Timestamps
+    ("timestamp.line", "{style} (e.g. '{example}'): `{tag}` -> {tag}"),
+    ("timestamp.short-time", "Short time"),
+    ("timestamp.short-time.example", "16:20"),
+    ("timestamp.long-time", "Long time"),
+    ("timestamp.long-time.example", "16:20:30"),
+    ("timestamp.short-date", "Short date"),
+    ("timestamp.short-date.example", "20/04/2021"),
+    ("timestamp.long-date", "Long date"),
+    ("timestamp.long-date.example", "20 April 2021"),
+    ("timestamp.short-date-time", "Short date/time"),
+    ("timestamp.short-date-time.example", "20 April 2021 16:20"),
+    ("timestamp.long-date-time", "Long date/time"),
+    ("timestamp.long-date-time.example", "Tuesday, 20 April 2021 16:20"),
+    ("timestamp.relative", "Relative"),
+    ("timestamp.relative.example", "2 months ago"),
+    // Expressions
+    ("error.expression.unknown-unit", "Unknown unit: '{unit}'"),
+    ("error.expression.unexpected-token", "Unexpected '{token}' in expression"),
+    ("error.expression.unexpected-end", "The expression ended unexpectedly"),
+    ("error.expression.division-by-zero", "Can't divide by zero"),
+    ("error.expression.add", "Can't add {left} and {right}"),
+    ("error.expression.subtract", "Can't subtract {left} and {right}"),
+    ("error.expression.multiply", "Can't multiply {left} and {right}"),
+    ("error.expression.divide", "Can't divide {left} and {right}"),
+    ("error.expression.convert", "Can't convert {left} and {right}"),
+    ("expression.unit", "{unit} ({dimension})"),
+    ("expression.plain-number", "a plain number"),
+    ("dimension.length", "length"),
+    ("dimension.area", "area"),
+    ("dimension.volume", "volume"),
+    ("dimension.energy", "energy"),
+    ("dimension.power", "power"),
+    ("dimension.pressure", "pressure"),
+    ("dimension.data", "data"),
+    ("dimension.currency", "currency"),
+    // Feels like
+    ("error.feels-like.invalid-humidity", "Invalid relative humidity: '{humidity}' (expected a percentage from 0 to 100)"),
+    ("error.feels-like.invalid-wind-speed", "Invalid wind speed: '{wind}' (e.g. '20 km/h', '12 mph', '5 m/s', '10 kn')"),
+    ("error.feels-like.nothing-to-calculate", "Provide a humidity, a wind speed, or both"),
+    ("feels-like.humidity", ", {humidity}% humidity"),
+    ("feels-like.wind", ", {wind} km/h wind"),
+    ("feels-like.heat-index", "Heat index"),
+    ("feels-like.dew-point", "Dew point"),
+    ("feels-like.humidex", "Humidex"),
+    ("feels-like.wind-chill", "Wind chill"),
+    ("feels-like.warning", " (warning: {warning})"),
+    ("feels-like.heat-index-temperature", "the heat index is only meaningful at 80°F (26.7°C) or above"),
+    ("feels-like.heat-index-humidity", "the heat index is only meaningful at 40% humidity or above"),
+    ("feels-like.wind-chill-temperature", "wind chill is only defined at 10°C (50°F) or below"),
+    ("feels-like.wind-chill-speed", "wind chill is only defined for winds of 4.8 km/h (3 mph) or more"),
+    ("feels-like.dew-point-temperature", "the dew point formula is only accurate from -45°C to 60°C"),
+    ("feels-like.dew-point-humidity", "the dew point formula is only accurate at 1% humidity or above"),
+    ("feels-like.humidex-temperature", "the humidex is only reported at 20°C (68°F) or above"),
+    // Preferences
+    ("error.preferences.storage", "Couldn't read or write preferences: {error}"),
+    ("error.preferences.invalid-locale", "Invalid locale: '{locale}' (e.g. 'en-GB', 'de')"),
+    // Scanning messages
+    ("error.scan.nothing-found", "Couldn't find any temperatures, amounts of money or times to convert"),
+];
+
+/// Translations of replies, and of command metadata: names ('name.' and the
+/// English name), descriptions (the command's path, e.g.
+/// 'conversions.temperature.value') and choices ('choice.' and the value).
+const GERMAN: &[(&str, &str)] = &[
+    ("reply.input", "Eingabe"),
+    ("reply.output", "Ausgabe"),
+    ("reply.units", "Einheiten"),
+    ("reply.rate", "Kurs"),
+    ("reply.rate-updated", "{rate} (aktualisiert {when})"),
+    ("reply.warnings", "Warnungen"),
+    ("reply.warning", "(Warnung: {warning})"),
+    ("reply.error", "Fehler"),
+    ("reply.error-prefix", "Fehler: {error}"),
+    ("reply.source", "Daten von {source}"),
+    ("error.missing-option", "Option '{name}' fehlt"),
+    ("error.not-a-number", "Option '{name}' muss eine Zahl sein"),
+    ("error.unknown-subcommand", "Unbekannter Unterbefehl '{name}'"),
+    ("error.no-subcommand", "Kein Unterbefehl angegeben"),
+    ("error.no-message", "Keine Nachricht angegeben"),
+    (
+        "error.no-zone",
+        "Option 'target' fehlt, und es ist keine bevorzugte Zeitzone festgelegt",
+    ),
+    (
+        "error.not-in-guild",
+        "Serverstandards können nur auf einem Server geändert werden",
+    ),
+    (
+        "error.missing-permission",
+        "Um die Serverstandards zu ändern, brauchst du die Berechtigung „Server verwalten“",
+    ),
+    ("settings.user", "Deine Einstellungen"),
+    ("settings.guild", "Standards dieses Servers"),
+    ("settings.temperature", "Temperatur"),
+    ("settings.currency", "Währung"),
+    ("settings.notation", "Zeitschreibweise"),
+    ("settings.zone", "Zeitzone"),
+    ("settings.locale", "Sprache"),
+    ("settings.unset", "nicht festgelegt"),
+    // Temperatures
+    ("error.invalid-number", "Ungültige Zahl angegeben: {number}"),
+    ("error.temperature.invalid-unit", "Ungültige Einheit angegeben"),
+    ("error.temperature.gas-mark-difference", "Gasstufen können nicht für Temperaturdifferenzen verwendet werden"),
+    ("error.temperature.below-absolute-zero", "{value} liegt unter dem absoluten Nullpunkt"),
+    ("error.temperature.not-finite", "{value} ist keine endliche Zahl"),
+    ("error.temperature.out-of-range", "{value} liegt außerhalb des gültigen Bereichs"),
+    ("temperature.gas-mark", "Gasstufe {mark}"),
+    ("temperature.below-gas-marks", "Unter Gasstufe 1/4"),
+    ("temperature.above-gas-marks", "Über Gasstufe 10"),
+    ("unit.kelvin", "Kelvin"),
+    ("unit.celsius", "Grad Celsius"),
+    ("unit.fahrenheit", "Grad Fahrenheit"),
+    ("unit.rankine", "Grad Rankine"),
+    ("unit.reaumur", "Grad Réaumur"),
+    ("unit.delisle", "Grad Delisle"),
+    ("unit.newton", "Grad Newton"),
+    ("unit.romer", "Grad Rømer"),
+    ("unit.gasmark", "Gasstufe"),
+    // Lengths
+    ("unit.mm", "Millimeter"),
+    ("unit.cm", "Zentimeter"),
+    ("unit.m", "Meter"),
+    ("unit.km", "Kilometer"),
+    ("unit.in", "Zoll"),
+    ("unit.ft", "Fuß"),
+    ("unit.yd", "Yard(s)"),
+    ("unit.mi", "Meile(n)"),
+    // Areas
+    ("unit.m²", "Quadratmeter"),
+    ("unit.km²", "Quadratkilometer"),
+    ("unit.ft²", "Quadratfuß"),
+    ("unit.yd²", "Quadratyard(s)"),
+    ("unit.ac", "Acre(s)"),
+    ("unit.ha", "Hektar"),
+    // Energy
+    ("unit.J", "Joule"),
+    ("unit.kJ", "Kilojoule"),
+    ("unit.cal", "Kalorie(n)"),
+    ("unit.kcal", "Kilokalorie(n)"),
+    ("unit.kWh", "Kilowattstunde(n)"),
+    ("unit.BTU", "British Thermal Unit(s)"),
+    ("unit.eV", "Elektronenvolt"),
+    // Power
+    ("unit.W", "Watt"),
+    ("unit.kW", "Kilowatt"),
+    ("unit.hp", "Mechanische Pferdestärke(n)"),
+    ("unit.PS", "Metrische Pferdestärke(n)"),
+    // Pressure
+    ("unit.Pa", "Pascal"),
+    ("unit.kPa", "Kilopascal"),
+    ("unit.bar", "Bar"),
+    ("unit.psi", "Pfund pro Quadratzoll"),
+    ("unit.atm", "Atmosphäre(n)"),
+    ("unit.mmHg", "Millimeter Quecksilbersäule"),
+    ("unit.inHg", "Zoll Quecksilbersäule"),
+    // Volumes
+    ("unit.mL", "Milliliter"),
+    ("unit.L", "Liter"),
+    ("unit.tsp", "Teelöffel"),
+    ("unit.tbsp", "Esslöffel"),
+    ("unit.fl oz", "Flüssigunze(n)"),
+    ("unit.cup", "Tasse(n)"),
+    ("unit.pt", "Pint(s)"),
+    ("unit.qt", "Quart(s)"),
+    ("unit.gal", "Gallone(n)"),
+    // Durations
+    ("unit.ms", "Millisekunde(n)"),
+    ("unit.s", "Sekunde(n)"),
+    ("unit.min", "Minute(n)"),
+    ("unit.h", "Stunde(n)"),
+    ("unit.d", "Tag(e)"),
+    ("unit.wk", "Woche(n)"),
+    // Data
+    ("error.invalid-unit", "Ungültige Einheit angegeben: '{unit}'"),
+    ("error.data.unexpected-rate", "'{unit}' ist eine Datenrate, erwartet wurde eine Größe (z. B. 'MB', 'GiB')"),
+    ("error.data.expected-rate", "'{unit}' ist eine Größe, erwartet wurde eine Datenrate (z. B. 'Mbps', 'MB/s')"),
+    ("error.data.not-positive-rate", "Die Übertragungsrate muss größer als null sein"),
+    ("error.data.too-long", "Die Übertragung von {size} würde länger als 30 Millionen Jahre dauern"),
+    ("data.unit", "{name} [{symbol}]"),
+    ("data.per-second", "{unit} pro Sekunde"),
+    ("data.transfer", "{size} mit {rate}"),
+    ("data.bits-iec", "1 {symbol} = {power} Bit (IEC, binär)"),
+    ("data.bits-si", "1 {symbol} = {power} Bit (SI, dezimal)"),
+    ("data.bytes-iec", "1 {symbol} = {power} Byte (IEC, binär)"),
+    ("data.bytes-si", "1 {symbol} = {power} Byte (SI, dezimal)"),
+    // Currencies
+    ("error.currency.invalid-currency", "Ungültige Währung angegeben: '{input}'"),
+    ("error.currency.invalid-target", "Ungültige Zielwährung"),
+    ("error.currency.request", "Daten konnten nicht von der Währungs-API abgerufen werden: {message}"),
+    ("error.currency.json", "Die Daten der Währungs-API konnten nicht gelesen werden"),
+    ("currency.usd", "US-Dollar [USD]"),
+    ("currency.eur", "Euro [EUR]"),
+    ("currency.cad", "Kanadische(r) Dollar [CAD]"),
+    ("currency.rub", "Rubel [RUB]"),
+    ("currency.jpy", "Yen [JPY]"),
+    ("currency.aud", "Australische(r) Dollar [AUD]"),
+    ("currency.amd", "Dram [AMD]"),
+    ("currency.gbp", "Britische(s) Pfund [GBP]"),
+    ("currency.pkr", "Pakistanische Rupie(n) [PKR]"),
+    // Times
+    ("error.time.improper-form", "'{time}' hat ein ungültiges Format: {error}. Beispiele: '12:20 PM' oder '17:00:08'."),
+    ("error.time.too-many-sections", "Die angegebene Uhrzeit hat zu viele Abschnitte"),
+    ("error.time.hour-out-of-range", "Stunde {hour} liegt außerhalb des Bereichs (0 bis 23)"),
+    ("error.time.minute-out-of-range", "Minute {minute} liegt außerhalb des Bereichs (0 bis 59)"),
+    ("error.time.second-out-of-range", "Sekunde {second} liegt außerhalb des Bereichs (0 bis 59)"),
+    ("error.time.invalid-twelve-hour-hour", "Stunde {hour} gibt es im 12-Stunden-Format nicht (1 bis 12)"),
+    ("error.time.unrecognised", "'{time}' konnte nicht als Uhrzeit gelesen werden"),
+    ("error.time.notation-out-of-range", "'{time}' liegt außerhalb des Bereichs seiner Schreibweise"),
+    ("error.time.invalid-notation", "Ungültige Zeitschreibweise: '{notation}' (erwartet '12h', '24h', 'decimal', 'beats' oder 'decimal hours')"),
+    ("time.normalised", "Normalisiert aus '{time}'{days}"),
+    ("time.next-day", ", am nächsten Tag"),
+    ("time.days-later", ", {days} Tage später"),
+    // Time zones
+    ("error.timezone.unknown", "Unbekannte Zeitzone: '{zone}' (z. B. 'Europe/London', 'EST', 'Tokyo', 'UTC+5:30')"),
+    ("error.timezone.missing", "Keine Zeitzone angegeben (z. B. '3pm EST', '14:30 Europe/London')"),
+    ("error.timezone.skipped", "{time} gibt es am {date} in {zone} nicht, die Uhren überspringen diese Zeit"),
+    ("day-offset.next", " (nächster Tag)"),
+    ("day-offset.previous", " (vorheriger Tag)"),
+    ("day-offset.days", " ({days} Tage)"),
+    // Dates
+    ("error.date.unrecognised", "'{date}' konnte nicht als Datum gelesen werden (z. B. '2023-07-15', '15/07/2023', '15 July 2023', '1689379200')"),
+    ("error.date.out-of-range", "'{date}' ist kein echtes Datum"),
+    ("error.date.invalid-order", "Ungültige Datumsreihenfolge: '{order}' (erwartet 'dmy' oder 'mdy')"),
+    ("error.date.invalid-format", "Ungültiges Datumsformat: '{format}' (erwartet 'iso', 'rfc2822', 'us', 'eu', 'unix' oder 'unix ms')"),
+    ("date.ambiguous", "Hinweis: '{date}' wurde als {order} gelesen. Lege die Reihenfolge fest, um das zu ändern"),
+    ("date.day-month-year", "Tag/Monat/Jahr"),
+    ("date.month-day-year", "Monat/Tag/Jahr"),
+    ("date.description", "{weekday}, ISO-Woche {week}, Tag {day} von {days}"),
+    ("weekday.monday", "Montag"),
+    ("weekday.tuesday", "Dienstag"),
+    ("weekday.wednesday", "Mittwoch"),
+    ("weekday.thursday", "Donnerstag"),
+    ("weekday.friday", "Freitag"),
+    ("weekday.saturday", "Samstag"),
+    ("weekday.sunday", "Sonntag"),
+    // Durations in words
+    ("error.duration.invalid", "'{input}' konnte nicht als Dauer gelesen werden (z. B. '1h30m', '90 minutes', 'PT1H30M')"),
+    ("error.duration.missing-unit", "'{number}' braucht eine Einheit (z. B. '{number} minutes')"),
+    ("error.duration.non-fixed-unit", "Jahre und Monate haben keine feste Länge, daher kann '{input}' nicht verwendet werden"),
+    ("error.duration.invalid-calculation", "'{input}' konnte nicht berechnet werden (z. B. '14:30 + 2h45m', '17:40 - 09:15', '1h + 45m')"),
+    ("duration.range", "{start} bis {end}"),
+    ("duration.negative", "minus {duration}"),
+    ("duration.week", "{amount} Woche"),
+    ("duration.weeks", "{amount} Wochen"),
+    ("duration.day", "{amount} Tag"),
+    ("duration.days", "{amount} Tage"),
+    ("duration.hour", "{amount} Stunde"),
+    ("duration.hours", "{amount} Stunden"),
+    ("duration.minute", "{amount} Minute"),
+    ("duration.minutes", "{amount} Minuten"),
+    ("duration.second", "{amount} Sekunde"),
+    ("duration.seconds", "{amount} Sekunden"),
+    ("duration.millisecond", "{amount} Millisekunde"),
+    ("duration.milliseconds", "{amount} Millisekunden"),
+    ("list.and", "{list} und {last}"),
+    // Countdowns
+    ("error.countdown.unrecognised", "'{target}' konnte nicht gelesen werden (z. B. '6pm Friday UTC', 'Dec 25', '2023-12-25 18:00 EST')"),
+    ("countdown.date", "{weekday}, {day}. {month} {year}, {time}"),
+    ("countdown.past", "{when} war vor {words} ({duration})"),
+    ("countdown.future", "{when} ist in {words} ({duration})"),
+    ("month.january", "Januar"),
+    ("month.february", "Februar"),
+    ("month.march", "März"),
+    ("month.april", "April"),
+    ("month.may", "Mai"),
+    ("month.june", "Juni"),
+    ("month.july", "Juli"),
+    ("month.august", "August"),
+    ("month.september", "September"),
+    ("month.october", "Oktober"),
+    ("month.november", "November"),
+    ("month.december", "Dezember"),
+    // Timestamps
+    ("timestamp.line", "{style} (z. B. '{example}'): `{tag}` -> {tag}"),
+    ("timestamp.short-time", "Kurze Uhrzeit"),
+    ("timestamp.short-time.example", "16:20"),
+    ("timestamp.long-time", "Lange Uhrzeit"),
+    ("timestamp.long-time.example", "16:20:30"),
+    ("timestamp.short-date", "Kurzes Datum"),
+    ("timestamp.short-date.example", "20.04.2021"),
+    ("timestamp.long-date", "Langes Datum"),
+    ("timestamp.long-date.example", "20. April 2021"),
+    ("timestamp.short-date-time", "Kurzes Datum mit Uhrzeit"),
+    ("timestamp.short-date-time.example", "20. April 2021 16:20"),
+    ("timestamp.long-date-time", "Langes Datum mit Uhrzeit"),
+    ("timestamp.long-date-time.example", "Dienstag, 20. April 2021 16:20"),
+    ("timestamp.relative", "Relativ"),
+    ("timestamp.relative.example", "vor 2 Monaten"),
+    // Expressions
+    ("error.expression.unknown-unit", "Unbekannte Einheit: '{unit}'"),
+    ("error.expression.unexpected-token", "Unerwartetes '{token}' im Ausdruck"),
+    ("error.expression.unexpected-end", "Der Ausdruck endete unerwartet"),
+    ("error.expression.division-by-zero", "Division durch null ist nicht möglich"),
+    ("error.expression.add", "{left} und {right} können nicht addiert werden"),
+    ("error.expression.subtract", "{left} und {right} können nicht subtrahiert werden"),
+    ("error.expression.multiply", "{left} und {right} können nicht multipliziert werden"),
+    ("error.expression.divide", "{left} und {right} können nicht dividiert werden"),
+    ("error.expression.convert", "{left} und {right} können nicht umgerechnet werden"),
+    ("expression.unit", "{unit} ({dimension})"),
+    ("expression.plain-number", "eine reine Zahl"),
+    ("dimension.length", "Länge"),
+    ("dimension.area", "Fläche"),
+    ("dimension.volume", "Volumen"),
+    ("dimension.energy", "Energie"),
+    ("dimension.power", "Leistung"),
+    ("dimension.pressure", "Druck"),
+    ("dimension.data", "Daten"),
+    ("dimension.currency", "Währung"),
+    // Feels like
+    ("error.feels-like.invalid-humidity", "Ungültige relative Luftfeuchtigkeit: '{humidity}' (erwartet ein Prozentsatz von 0 bis 100)"),
+    ("error.feels-like.invalid-wind-speed", "Ungültige Windgeschwindigkeit: '{wind}' (z. B. '20 km/h', '12 mph', '5 m/s', '10 kn')"),
+    ("error.feels-like.nothing-to-calculate", "Gib eine Luftfeuchtigkeit, eine Windgeschwindigkeit oder beides an"),
+    ("feels-like.humidity", ", {humidity} % Luftfeuchtigkeit"),
+    ("feels-like.wind", ", {wind} km/h Wind"),
+    ("feels-like.heat-index", "Hitzeindex"),
+    ("feels-like.dew-point", "Taupunkt"),
+    ("feels-like.humidex", "Humidex"),
+    ("feels-like.wind-chill", "Windchill"),
+    ("feels-like.warning", " (Warnung: {warning})"),
+    ("feels-like.heat-index-temperature", "der Hitzeindex ist erst ab 26,7 °C (80 °F) aussagekräftig"),
+    ("feels-like.heat-index-humidity", "der Hitzeindex ist erst ab 40 % Luftfeuchtigkeit aussagekräftig"),
+    ("feels-like.wind-chill-temperature", "der Windchill ist nur bis 10 °C (50 °F) definiert"),
+    ("feels-like.wind-chill-speed", "der Windchill ist nur für Wind ab 4,8 km/h (3 mph) definiert"),
+    ("feels-like.dew-point-temperature", "die Taupunktformel ist nur von -45 °C bis 60 °C genau"),
+    ("feels-like.dew-point-humidity", "die Taupunktformel ist erst ab 1 % Luftfeuchtigkeit genau"),
+    ("feels-like.humidex-temperature", "der Humidex wird erst ab 20 °C (68 °F) angegeben"),
+    // Preferences
+    ("error.preferences.storage", "Einstellungen konnten nicht gelesen oder geschrieben werden: {error}"),
+    ("error.preferences.invalid-locale", "Ungültige Sprache: '{locale}' (z. B. 'en-GB', 'de')"),
+    // Scanning messages
+    ("error.scan.nothing-found", "Keine Temperaturen, Geldbeträge oder Uhrzeiten zum Umrechnen gefunden"),
+    // Names
+    ("name.Convert units", "Einheiten umrechnen"),
+    ("name.conversions", "umrechnungen"),
+    ("name.convert", "umrechnen"),
+    ("name.temperature", "temperatur"),
+    ("name.currency", "währung"),
+    ("name.data", "daten"),
+    ("name.transfer", "übertragung"),
+    ("name.area", "fläche"),
+    ("name.energy", "energie"),
+    ("name.power", "leistung"),
+    ("name.pressure", "druck"),
+    ("name.calculate", "berechnen"),
+    ("name.feels-like", "gefühlt"),
+    ("name.hours", "stunden"),
+    ("name.timezone", "zeitzone"),
+    ("name.date", "datum"),
+    ("name.duration", "dauer"),
+    ("name.timestamp", "zeitstempel"),
+    ("name.countdown", "countdown"),
+    ("name.settings", "einstellungen"),
+    ("name.value", "wert"),
+    ("name.target", "ziel"),
+    ("name.difference", "differenz"),
+    ("name.input", "eingabe"),
+    ("name.size", "größe"),
+    ("name.rate", "rate"),
+    ("name.expression", "ausdruck"),
+    ("name.humidity", "luftfeuchtigkeit"),
+    ("name.wind", "wind"),
+    ("name.time", "zeit"),
+    ("name.lenient", "tolerant"),
+    ("name.order", "reihenfolge"),
+    ("name.zone", "zone"),
+    ("name.unit", "einheit"),
+    ("name.notation", "schreibweise"),
+    ("name.locale", "sprache"),
+    ("name.reset", "zurücksetzen"),
+    ("name.server", "server"),
+    // `/conversions`
+    ("conversions", "Zwischen Werten umrechnen"),
+    (
+        "conversions.temperature",
+        "Temperatureinheiten umrechnen. Unterstützt K, °C, °F, °R, °Ré, °De, °N, °Rø und Gasstufen.",
+    ),
+    (
+        "conversions.temperature.value",
+        "Ausgangswert (z. B. '65F' [Fahrenheit], '18.33C' [Celsius], 'gas mark 4').",
+    ),
+    (
+        "conversions.temperature.target",
+        "Zieleinheit (z. B. 'F', 'K', 'gas mark'). Standard ist deine bevorzugte Skala.",
+    ),
+    (
+        "conversions.temperature.difference",
+        "Den Wert als Differenz (z. B. 10°C wärmer) statt als Messwert behandeln.",
+    ),
+    (
+        "conversions.currency",
+        "Von einer Währung in eine andere umrechnen.",
+    ),
+    (
+        "conversions.currency.input",
+        "Der Betrag (z. B. '$74', '80.90 CAD', '20 quid').",
+    ),
+    (
+        "conversions.currency.target",
+        "Zielwährung: USD, EUR, CAD, RUB, JPY, AUD, AMD, GBP oder PKR.",
+    ),
+    (
+        "conversions.data",
+        "Datengrößen oder -raten umrechnen. Unterstützt SI- (kB, MB) und IEC-Einheiten (KiB, MiB).",
+    ),
+    (
+        "conversions.data.value",
+        "Ausgangswert (z. B. '50 GiB', '100 Mbps', '12.5 MB/s').",
+    ),
+    (
+        "conversions.data.target",
+        "Zieleinheit (z. B. 'GB', 'MiB', 'MB/s').",
+    ),
+    (
+        "conversions.transfer",
+        "Schätzen, wie lange eine Übertragung dauert.",
+    ),
+    (
+        "conversions.transfer.size",
+        "Die Datenmenge (z. B. '50 GiB', '700 MB').",
+    ),
+    (
+        "conversions.transfer.rate",
+        "Die Übertragungsrate (z. B. '100 Mbps', '2 MB/s').",
+    ),
+    (
+        "conversions.area",
+        "Flächeneinheiten umrechnen. Unterstützt m², km², ft², yd², Acres und Hektar.",
+    ),
+    (
+        "conversions.area.value",
+        "Ausgangswert (z. B. '3 acres', '1000 sq ft').",
+    ),
+    (
+        "conversions.area.target",
+        "Zieleinheit (z. B. 'ha' [Hektar], 'm2' [Quadratmeter]).",
+    ),
+    (
+        "conversions.energy",
+        "Energieeinheiten umrechnen. Unterstützt J, kJ, cal, kcal, kWh, BTU und eV.",
+    ),
+    (
+        "conversions.energy.value",
+        "Ausgangswert (z. B. '250 kcal', '1.5 kWh').",
+    ),
+    (
+        "conversions.energy.target",
+        "Zieleinheit (z. B. 'kJ' [Kilojoule], 'BTU').",
+    ),
+    (
+        "conversions.power",
+        "Leistungseinheiten umrechnen. Unterstützt W, kW, mechanische hp und metrische PS.",
+    ),
+    (
+        "conversions.power.value",
+        "Ausgangswert (z. B. '150 hp', '7.5 kW').",
+    ),
+    (
+        "conversions.power.target",
+        "Zieleinheit (z. B. 'kW' [Kilowatt], 'PS' [metrische Pferdestärke]).",
+    ),
+    (
+        "conversions.pressure",
+        "Druckeinheiten umrechnen. Unterstützt Pa, kPa, bar, psi, atm, mmHg und inHg.",
+    ),
+    (
+        "conversions.pressure.value",
+        "Ausgangswert (z. B. '32 psi', '101.3 kPa').",
+    ),
+    (
+        "conversions.pressure.target",
+        "Zieleinheit (z. B. 'bar', 'inHg' [Zoll Quecksilbersäule]).",
+    ),
+    (
+        "conversions.calculate",
+        "Mit Einheiten rechnen und das Ergebnis umrechnen.",
+    ),
+    (
+        "conversions.calculate.expression",
+        "Die Rechnung (z. B. '5 ft + 30 cm in m', '3 * 250 ml to cups', '$20 + €15 to GBP').",
+    ),
+    (
+        "conversions.feels-like",
+        "Hitzeindex, Taupunkt, Humidex oder Windchill berechnen.",
+    ),
+    (
+        "conversions.feels-like.temperature",
+        "Die Lufttemperatur (z. B. '86F', '30C').",
+    ),
+    (
+        "conversions.feels-like.humidity",
+        "Relative Luftfeuchtigkeit (z. B. '70%') für Hitzeindex, Taupunkt und Humidex.",
+    ),
+    (
+        "conversions.feels-like.wind",
+        "Windgeschwindigkeit (z. B. '20 km/h', '12 mph', '5 m/s') für den Windchill.",
+    ),
+    (
+        "conversions.feels-like.target",
+        "Einheit der Ergebnisse (z. B. 'F', 'C'). Standard ist die Einheit der Temperatur.",
+    ),
+    (
+        "conversions.hours",
+        "Manche können einfach nicht '12' abziehen.",
+    ),
+    (
+        "conversions.hours.time",
+        "Zeit im 24h-Format ('6:00', '14:30') oder im 12h-Format ('4:44am', '6:00pm')",
+    ),
+    (
+        "conversions.hours.lenient",
+        "Ungültige Zeiten normalisieren (z. B. '24:00' ist Mitternacht am Folgetag).",
+    ),
+    (
+        "conversions.hours.target",
+        "Schreibweise der Zeit: '12h', '24h', 'decimal', 'beats' oder 'decimal hours'.",
+    ),
+    (
+        "conversions.timezone",
+        "Eine Uhrzeit in eine andere Zeitzone umrechnen, mit Sommerzeit.",
+    ),
+    (
+        "conversions.timezone.time",
+        "Zeit und Zone (z. B. '3pm EST', '14:30 Europe/London', '9am Tokyo').",
+    ),
+    (
+        "conversions.timezone.target",
+        "Die Zielzone (z. B. 'Asia/Tokyo', 'PT', 'Berlin'). Standard ist deine Zone.",
+    ),
+    (
+        "conversions.date",
+        "Ein Datum in andere Formate umrechnen und seinen Wochentag finden.",
+    ),
+    (
+        "conversions.date.value",
+        "Das Datum (z. B. '2023-07-15', '15/07/2023', 'July 15th 2023', '1689379200').",
+    ),
+    (
+        "conversions.date.target",
+        "Zielformat ('iso', 'rfc2822', 'us', 'eu', 'unix', 'unix ms'). Standard sind alle.",
+    ),
+    (
+        "conversions.date.order",
+        "Wie Daten wie '05/07/2023' gelesen werden ('dmy' oder 'mdy'). Standard ist 'dmy'.",
+    ),
+    (
+        "conversions.duration",
+        "Dauern umrechnen, zu Zeiten addieren oder die Zeit zwischen zwei Zeiten finden.",
+    ),
+    (
+        "conversions.duration.input",
+        "z. B. '1h30m', 'PT1H30M', '14:30 + 2h45m', 'from 09:15 to 17:40'.",
+    ),
+    (
+        "conversions.duration.target",
+        "Zieleinheit (z. B. 'minutes', 'hours', 'days').",
+    ),
+    (
+        "conversions.timestamp",
+        "Discord-Zeitstempel erstellen, die jeder in seiner eigenen Zeitzone sieht.",
+    ),
+    (
+        "conversions.timestamp.time",
+        "Die Zeit, optional mit Zone (z. B. '3pm', '14:30 Europe/London').",
+    ),
+    (
+        "conversions.timestamp.date",
+        "Das Datum (z. B. '2023-07-15', '15 July 2023'). Standard ist heute.",
+    ),
+    (
+        "conversions.timestamp.zone",
+        "Die Zeitzone (z. B. 'EST', 'Tokyo', 'UTC+5:30'). Standard ist deine Zone oder UTC.",
+    ),
+    (
+        "conversions.countdown",
+        "Herausfinden, wie lange es bis zu einer Zeit, einem Wochentag oder Datum dauert.",
+    ),
+    (
+        "conversions.countdown.target",
+        "z. B. '6pm Friday UTC', 'Dec 25', 'next Monday at 9am', '2024-01-01 00:00 EST'.",
+    ),
+    (
+        "conversions.countdown.zone",
+        "Die Zeitzone, falls nicht beim Ziel angegeben (z. B. 'Tokyo'). Standard: deine Zone, UTC.",
+    ),
+    (
+        "conversions.settings",
+        "Die Einheiten ansehen oder ändern, in die standardmäßig umgerechnet wird.",
+    ),
+    (
+        "conversions.settings.temperature",
+        "Deine bevorzugte Temperaturskala.",
+    ),
+    ("conversions.settings.currency", "Deine bevorzugte Währung."),
+    (
+        "conversions.settings.notation",
+        "Ob du 12h- oder 24h-Zeit bevorzugst, oder eine andere Schreibweise.",
+    ),
+    (
+        "conversions.settings.zone",
+        "Deine Zeitzone (z. B. 'Europe/Berlin', 'CET', 'Tokyo', 'UTC+5:30').",
+    ),
+    (
+        "conversions.settings.locale",
+        "Deine Sprache und Region (z. B. 'de', 'en-GB').",
+    ),
+    (
+        "conversions.settings.reset",
+        "Deine Einstellungen löschen, bevor die hier angegebenen übernommen werden.",
+    ),
+    (
+        "conversions.settings.server",
+        "Die Standards des Servers statt deiner ändern. Braucht die Berechtigung „Server verwalten“.",
+    ),
+    // `/convert`
+    (
+        "convert",
+        "Eine Zahl von einer Einheit in eine andere umrechnen",
+    ),
+    (
+        "convert.temperature",
+        "Eine Zahl von einer Temperatureinheit in eine andere umrechnen.",
+    ),
+    (
+        "convert.temperature.value",
+        "Die umzurechnende Zahl (z. B. 65).",
+    ),
+    ("convert.temperature.unit", "Die Einheit der Zahl."),
+    (
+        "convert.temperature.target",
+        "Die Zieleinheit. Standard ist deine bevorzugte Skala.",
+    ),
+    (
+        "convert.temperature.difference",
+        "Den Wert als Differenz (z. B. 10°C wärmer) statt als Messwert behandeln.",
+    ),
+    (
+        "convert.currency",
+        "Einen Betrag von einer Währung in eine andere umrechnen.",
+    ),
+    (
+        "convert.currency.value",
+        "Der umzurechnende Betrag (z. B. 74).",
+    ),
+    ("convert.currency.unit", "Die Währung des Betrags."),
+    (
+        "convert.currency.target",
+        "Die Zielwährung. Standard ist deine bevorzugte Währung.",
+    ),
+    (
+        "convert.data",
+        "Eine Zahl von einer Datengrößen- oder Rateneinheit in eine andere umrechnen.",
+    ),
+    ("convert.data.value", "Die umzurechnende Zahl (z. B. 50)."),
+    (
+        "convert.data.unit",
+        "Die Einheit der Zahl (z. B. 'GiB', 'Mbps').",
+    ),
+    (
+        "convert.data.target",
+        "Die Zieleinheit (z. B. 'GB', 'MB/s').",
+    ),
+    (
+        "convert.area",
+        "Eine Zahl von einer Flächeneinheit in eine andere umrechnen.",
+    ),
+    ("convert.area.value", "Die umzurechnende Zahl (z. B. 3.5)."),
+    ("convert.area.unit", "Die Einheit der Zahl."),
+    ("convert.area.target", "Die Zieleinheit."),
+    (
+        "convert.energy",
+        "Eine Zahl von einer Energieeinheit in eine andere umrechnen.",
+    ),
+    ("convert.energy.value", "Die umzurechnende Zahl (z. B. 3.5)."),
+    ("convert.energy.unit", "Die Einheit der Zahl."),
+    ("convert.energy.target", "Die Zieleinheit."),
+    (
+        "convert.power",
+        "Eine Zahl von einer Leistungseinheit in eine andere umrechnen.",
+    ),
+    ("convert.power.value", "Die umzurechnende Zahl (z. B. 3.5)."),
+    ("convert.power.unit", "Die Einheit der Zahl."),
+    ("convert.power.target", "Die Zieleinheit."),
+    (
+        "convert.pressure",
+        "Eine Zahl von einer Druckeinheit in eine andere umrechnen.",
+    ),
+    (
+        "convert.pressure.value",
+        "Die umzurechnende Zahl (z. B. 3.5).",
+    ),
+    ("convert.pressure.unit", "Die Einheit der Zahl."),
+    ("convert.pressure.target", "Die Zieleinheit."),
+    // Choices
+    ("choice.gasmark", "Gasstufe"),
+    ("choice.usd", "US-Dollar [USD]"),
+    ("choice.eur", "Euro [EUR]"),
+    ("choice.gbp", "Britisches Pfund [GBP]"),
+    ("choice.cad", "Kanadischer Dollar [CAD]"),
+    ("choice.aud", "Australischer Dollar [AUD]"),
+    ("choice.jpy", "Yen [JPY]"),
+    ("choice.rub", "Rubel [RUB]"),
+    ("choice.amd", "Dram [AMD]"),
+    ("choice.pkr", "Pakistanische Rupie [PKR]"),
+    ("choice.decimal", "Dezimalzeit"),
+    ("choice.beats", "Swatch-Beats"),
+    ("choice.decimal hours", "Dezimalstunden"),
+];
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_text()
+    {
+        assert_eq!(Locale::from_tag("de-AT"), Locale::German);
+        assert_eq!(Locale::from_tag("en-GB"), Locale::English);
+        assert_eq!(Locale::from_tag("pt-BR"), Locale::English);
+        assert_eq!(
+            Locale::German.text("error.missing-option", &[("name", "value")]),
+            "Option 'value' fehlt"
+        );
+        // Untranslated keys fall back to English, then to the key
+        assert_eq!(Locale::English.translate("conversions.temperature"), None);
+        assert_eq!(Locale::German.text("no.such.key", &[]), "no.such.key");
+    }
+
+    #[test]
+    fn test_catalogues_complete()
+    {
+        for (key, _) in ENGLISH
+        {
+            for locale in Locale::ALL
+            {
+                assert!(
+                    locale.translate(key).is_some(),
+                    "'{key}' isn't translated into {locale:?}"
+                );
+            }
+        }
+    }
+}
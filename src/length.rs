@@ -0,0 +1,94 @@
+use crate::{
+    i18n::Locale,
+    quantity::{LinearUnit, ParseUnitError, Quantity},
+};
+use std::{fmt, str::FromStr};
+
+/// A length, stored in metres.
+pub type Length = Quantity<LengthUnit>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum LengthUnit
+{
+    Millimetre,
+    Centimetre,
+    Metre,
+    Kilometre,
+    Inch,
+    Foot,
+    Yard,
+    Mile,
+}
+
+impl LinearUnit for LengthUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::Millimetre,
+        Self::Centimetre,
+        Self::Metre,
+        Self::Kilometre,
+        Self::Inch,
+        Self::Foot,
+        Self::Yard,
+        Self::Mile,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::Millimetre => 1e-3,
+            Self::Centimetre => 1e-2,
+            Self::Metre => 1.0,
+            Self::Kilometre => 1e3,
+            Self::Inch => 0.0254,
+            Self::Foot => 0.3048,
+            Self::Yard => 0.9144,
+            Self::Mile => 1609.344,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Millimetre => "mm",
+            Self::Centimetre => "cm",
+            Self::Metre => "m",
+            Self::Kilometre => "km",
+            Self::Inch => "in",
+            Self::Foot => "ft",
+            Self::Yard => "yd",
+            Self::Mile => "mi",
+        }
+    }
+}
+
+impl FromStr for LengthUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "mm" | "millimetre" | "millimetres" | "millimeter" | "millimeters" => Self::Millimetre,
+            "cm" | "centimetre" | "centimetres" | "centimeter" | "centimeters" => Self::Centimetre,
+            "m" | "metre" | "metres" | "meter" | "meters" => Self::Metre,
+            "km" | "kilometre" | "kilometres" | "kilometer" | "kilometers" => Self::Kilometre,
+            "in" | "\"" | "inch" | "inches" => Self::Inch,
+            "ft" | "'" | "foot" | "feet" => Self::Foot,
+            "yd" | "yard" | "yards" => Self::Yard,
+            "mi" | "mile" | "miles" => Self::Mile,
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for LengthUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
@@ -1,5 +1,6 @@
 use lazy_static::lazy_static;
-use serenity::{builder::CreateApplicationCommand, model::prelude::command::CommandOptionType};
+use serenity::{builder::CreateApplicationCommand, model::prelude::command::CommandType};
+use spec::CommandSpec;
 
 /// Temperature conversions
 pub mod temperature;
@@ -7,9 +8,81 @@ pub mod temperature;
 /// Conversions between 12 and 24 hour time
 pub mod time;
 
+/// Time zone conversions
+pub mod timezone;
+
+/// Date parsing and formatting
+pub mod date;
+
+/// Durations, and arithmetic on times
+pub mod duration;
+
+/// Discord timestamp markup
+pub mod timestamp;
+
+/// Countdowns to a time, day or date
+pub mod countdown;
+
 /// Currency conversion
 pub mod currency;
 
+/// Digital storage and data-rate conversions
+pub mod data;
+
+/// Shared support for quantities with linear units
+pub mod quantity;
+
+/// Area conversions
+pub mod area;
+
+/// Energy conversions
+pub mod energy;
+
+/// Power conversions
+pub mod power;
+
+/// Pressure conversions
+pub mod pressure;
+
+/// Length units
+pub mod length;
+
+/// Volume units
+pub mod volume;
+
+/// Arithmetic on quantities with units
+pub mod expression;
+
+/// Heat index, wind chill, dew point and humidex
+pub mod feels_like;
+
+/// User and guild preferences, and where they're kept
+pub mod preferences;
+
+/// Finding temperatures, amounts of money and times in messages
+pub mod scan;
+
+/// Converting what's found in chat, in channels where it's turned on
+pub mod listener;
+
+/// Message catalogues, for replies and command metadata in other languages
+pub mod i18n;
+
+/// Declarative specs for slash commands, from which registration and help are
+/// made
+pub mod spec;
+
+/// Structured conversion results, shown as plain text or embeds
+pub mod reply;
+
+/// Routing slash command interactions to the conversions
+pub mod handler;
+
+pub use handler::{autocomplete, handle, ConversionsState, Response};
+pub use i18n::Locale;
+pub use listener::Listener;
+pub use preferences::{JsonStore, MemoryStore, PreferenceStore};
+
 fn strip_suffixes(mut input: String, suffixes: &[&str]) -> String
 {
     for suffix in suffixes
@@ -24,130 +97,89 @@ fn strip_suffixes(mut input: String, suffixes: &[&str]) -> String
     input
 }
 
+/// The `/conversions` command, made from each module's subcommand.
+pub const COMMAND: CommandSpec = CommandSpec {
+    name: "conversions",
+    description: "Convert between values",
+    subcommands: &[
+        temperature::SUBCOMMAND,
+        currency::SUBCOMMAND,
+        data::SUBCOMMAND,
+        data::TRANSFER_SUBCOMMAND,
+        area::SUBCOMMAND,
+        energy::SUBCOMMAND,
+        power::SUBCOMMAND,
+        pressure::SUBCOMMAND,
+        expression::SUBCOMMAND,
+        feels_like::SUBCOMMAND,
+        time::SUBCOMMAND,
+        timezone::SUBCOMMAND,
+        date::SUBCOMMAND,
+        duration::SUBCOMMAND,
+        timestamp::SUBCOMMAND,
+        countdown::SUBCOMMAND,
+        preferences::SUBCOMMAND,
+    ],
+};
+
+/// The `/convert` command, an alternative to `/conversions` with the number
+/// and its units as separate typed options.
+pub const TYPED_COMMAND: CommandSpec = CommandSpec {
+    name: "convert",
+    description: "Convert a number from one unit to another",
+    subcommands: &[
+        temperature::TYPED_SUBCOMMAND,
+        currency::TYPED_SUBCOMMAND,
+        data::TYPED_SUBCOMMAND,
+        area::TYPED_SUBCOMMAND,
+        energy::TYPED_SUBCOMMAND,
+        power::TYPED_SUBCOMMAND,
+        pressure::TYPED_SUBCOMMAND,
+    ],
+};
+
 pub fn register(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand
 {
+    COMMAND.register(command)
+}
+
+/// The name of the message context-menu command.
+pub const MESSAGE_COMMAND: &str = "Convert units";
+
+/// Register the message context-menu command, which converts everything it
+/// finds in a message.
+pub fn register_message(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand
+{
+    command
+        .name(MESSAGE_COMMAND)
+        .kind(CommandType::Message)
+        .dm_permission(true);
+    for locale in Locale::ALL
+    {
+        if let Some(name) = locale.translate(&format!("name.{MESSAGE_COMMAND}"))
+        {
+            command.name_localized(locale.discord_code(), name);
+        }
+    }
     command
-        .name("conversions")
-        .description("Convert between values")
-        .dm_permission(true)
-        .create_option(|option| {
-            option
-                .name("temperature")
-                .kind(CommandOptionType::SubCommand)
-                .description("Convert from one temperature unit to another. Supports Kelvin, Fahrenheit, and Celcius.")
-                .create_sub_option(|option| {
-                    option
-                        .name("value")
-                        .description("Original value (e.g. '65F' [Fahrenheit], '18.33C' [Celsius].")
-                        .kind(CommandOptionType::String)
-                        .required(true)
-                })
-                .create_sub_option(|option| {
-                    option
-                        .name("target")
-                        .description("The unit to target. (e.g 'F' [Fahrenheit], 'K' [kelvin]).")
-                        .kind(CommandOptionType::String)
-                        .required(true)
-                })
-        })
-        .create_option(|option| {
-            option
-                .name("currency")
-                .kind(CommandOptionType::SubCommand)
-                .description("Convert from one currency to another.")
-                .create_sub_option(|option| {
-                    option
-                        .name("input")
-                        .description("The input currency (e.g. '$74', '80.90 CAD', '20 quid').")
-                        .kind(CommandOptionType::String)
-                        .required(true)
-                })
-                .create_sub_option(|option| {
-                    option
-                        .name("target")
-                        .description("The currency to convert to. (e.g 'rubles', 'usd', 'yen').")
-                        .kind(CommandOptionType::String)
-                        .required(true)
-                })
-        })
-        .create_option(|option| {
-            option
-                .name("hours")
-                .kind(CommandOptionType::SubCommand)
-                .description("Some people don't know how to subtract by '12'.")
-                .create_sub_option(|option| {
-                    option
-                        .name("time")
-                        .description(
-                            "Time in 24h time ('6:00', '14:30'), or in 12h time ('4:44am', '6:00pm')",
-                        )
-                        .kind(CommandOptionType::String)
-                        .required(true)
-                })
-        })
+}
+
+pub fn register_typed(command: &mut CreateApplicationCommand) -> &mut CreateApplicationCommand
+{
+    TYPED_COMMAND.register(command)
 }
 
 lazy_static! {
-    pub static ref HELP: String = {
-        help::HelpMessage::new()
-            .name("conversions")
-            .description("Convert between values")
-            .add_subcommand(
-                help::HelpMessage::new()
-                    .name("temperature")
-                    .description("Convert from one temperature unit to another. Supports Kelvin, Fahrenheit, and Celcius")
-                    .add_option({
-                        help::HelpMessageOption::new()
-                            .name("value")
-                            .kind("String")
-                            .description("Original value (e.g. '65F' [Fahrenheit], '18.33C' [Celsius])")
-                            .required(true)
-                            .clone()
-                    })
-                    .add_option({
-                        help::HelpMessageOption::new()
-                            .name("target")
-                            .kind("String")
-                            .description("The unit to target. (e.g 'F' [Fahrenheit], 'K' [kelvin])")
-                            .required(true)
-                            .clone()
-                    })
-                    .clone(),
-            )
-            .add_subcommand(help::HelpMessage::new()
-                    .name("currency")
-                    .description("Convert from one currency to another.")
-                    .add_option({
-                        help::HelpMessageOption::new()
-                            .name("input")
-                            .kind("String")
-                            .description("The input currency (e.g. '$74', '80.90 CAD', '20 quid')")
-                            .required(true)
-                            .clone()
-                    })
-                    .add_option({
-                        help::HelpMessageOption::new()
-                            .name("target")
-                            .kind("String")
-                            .description("The currency to convert to (e.g 'rubles', 'usd', 'yen'). Supported currencies: USD, EUR, CAD, RUB, JPY, AUD, AMD, and GBP")
-                            .required(true)
-                            .clone()
-                    })
-                    .clone()
-                )
-            .add_subcommand(
-                help::HelpMessage::new()
-                .name("hours")
-                .description("Some people don't know how to subtract by '12'")
-                .add_option(
-                    help::HelpMessageOption::new()
-                    .name("time")
-                    .description("Time in 24h time ('6:00', '14:30'), or in 12h time ('4:44am', '6:00pm')")
-                    .required(true)
-                    .kind("String")
-                    .clone()
-                ).clone()
-            )
-            .to_string()
-    };
+    pub static ref HELP: String = COMMAND.help(Locale::English).to_string();
+    pub static ref TYPED_HELP: String = TYPED_COMMAND.help(Locale::English).to_string();
+}
+
+/// The help message for `/conversions` and `/convert`, in `locale` where
+/// they've been translated.
+pub fn help(locale: Locale) -> (String, String)
+{
+    (
+        COMMAND.help(locale).to_string(),
+        TYPED_COMMAND.help(locale).to_string(),
+    )
 }
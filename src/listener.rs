@@ -0,0 +1,165 @@
+use crate::{
+    handler::ConversionsState,
+    i18n::Locale,
+    preferences::{Owner, Preferences},
+    reply::Reply,
+    scan,
+};
+use chrono::{DateTime, Duration, Utc};
+use serenity::model::{channel::Message, id::ChannelId};
+use std::collections::{HashMap, HashSet};
+
+/// Watches chat for temperatures, amounts of money and times (e.g. "it's 95F
+/// here", "costs £40") and converts them, in channels where it's been turned
+/// on. After replying in a channel, it stays quiet there for a while so it
+/// doesn't flood the conversation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Listener
+{
+    /// The channels to listen in, which is none until they're enabled
+    channels: HashSet<ChannelId>,
+
+    /// How long to wait after replying before replying in the same channel
+    cooldown: Duration,
+
+    /// When each channel was last replied in
+    last_reply: HashMap<ChannelId, DateTime<Utc>>,
+}
+
+impl Listener
+{
+    pub fn new(cooldown: Duration) -> Self
+    {
+        Self {
+            channels: HashSet::new(),
+            cooldown,
+            last_reply: HashMap::new(),
+        }
+    }
+
+    pub fn enable(&mut self, channel: ChannelId) { self.channels.insert(channel); }
+
+    pub fn disable(&mut self, channel: ChannelId)
+    {
+        self.channels.remove(&channel);
+        self.last_reply.remove(&channel);
+    }
+
+    pub fn is_enabled(&self, channel: ChannelId) -> bool { self.channels.contains(&channel) }
+
+    /// The conversions to reply to `message` with, in the guild's preferred
+    /// units and language. Nothing is returned if the channel isn't enabled, is cooling
+    /// down, or nothing convertible was found. Messages from bots are ignored,
+    /// so bots can't set each other off.
+    pub fn listen(&mut self, message: &Message, state: &ConversionsState) -> Option<Reply>
+    {
+        if message.author.bot
+        {
+            return None;
+        }
+        let preferred = message
+            .guild_id
+            .and_then(|guild| state.preferences(Owner::Guild(guild)).ok())
+            .unwrap_or_default();
+        self.respond(
+            message.channel_id,
+            &message.content,
+            Utc::now(),
+            &preferred,
+            state,
+        )
+    }
+
+    fn respond(
+        &mut self,
+        channel: ChannelId,
+        content: &str,
+        now: DateTime<Utc>,
+        preferred: &Preferences,
+        state: &ConversionsState,
+    ) -> Option<Reply>
+    {
+        if !self.is_enabled(channel)
+            || self
+                .last_reply
+                .get(&channel)
+                .is_some_and(|last| now - *last < self.cooldown)
+        {
+            return None;
+        }
+
+        // Conversions which failed (e.g. the exchange rates couldn't be
+        // fetched) are left out, since nobody asked for them
+        let locale = preferred
+            .locale
+            .as_deref()
+            .map_or(Locale::English, Locale::from_tag);
+        let lines: Vec<String> = scan::scan(content)
+            .into_iter()
+            .map(|found| {
+                state.with_converter(|converter| found.convert(preferred, converter, locale))
+            })
+            .filter(|reply| !matches!(reply, Reply::Error(_)))
+            .map(|reply| reply.text(locale))
+            .collect();
+        if lines.is_empty()
+        {
+            return None;
+        }
+
+        self.last_reply.insert(channel, now);
+        Some(Reply::Text(lines.join("\n")))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::currency::tests::converter;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_respond()
+    {
+        let state = ConversionsState::new(converter());
+        let preferred = Preferences::default();
+        let (general, other) = (ChannelId(1), ChannelId(2));
+        let now = Utc.with_ymd_and_hms(2023, 12, 20, 12, 0, 0).unwrap();
+
+        let mut listener = Listener::new(Duration::minutes(5));
+        listener.enable(general);
+        assert_eq!(
+            listener.respond(general, "it's 95F here", now, &preferred, &state),
+            Some(Reply::Text("95.00 Fahrenheit -> 35.00 Celsius".to_string()))
+        );
+        assert_eq!(
+            listener.respond(other, "it's 95F here", now, &preferred, &state),
+            None
+        );
+
+        // Cooling down
+        let later = now + Duration::minutes(1);
+        assert_eq!(
+            listener.respond(general, "costs £40", later, &preferred, &state),
+            None
+        );
+        let later = now + Duration::minutes(5);
+        assert!(listener
+            .respond(general, "costs £40", later, &preferred, &state)
+            .is_some());
+        assert_eq!(
+            listener.respond(
+                general,
+                "nothing",
+                later + Duration::hours(1),
+                &preferred,
+                &state
+            ),
+            None
+        );
+
+        listener.disable(general);
+        assert!(!listener.is_enabled(general));
+    }
+}
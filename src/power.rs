@@ -0,0 +1,129 @@
+use crate::{
+    i18n::Locale,
+    quantity::{self, LinearUnit, ParseUnitError, Quantity},
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+};
+use std::{fmt, str::FromStr};
+
+/// The `power` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "power",
+    "Convert from one power unit to another. Supports W, kW, mechanical hp, and metric hp (PS).",
+)
+.options(&[
+    OptionSpec::string("value", "Original value (e.g. '150 hp', '7.5 kW')."),
+    OptionSpec::string(
+        "target",
+        "The unit to target. (e.g 'kW' [kilowatts], 'PS' [metric horsepower]).",
+    ),
+]);
+
+/// The `power` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec =
+    SubcommandSpec::new("power", "Convert a number from one power unit to another.")
+        .options(&quantity::typed_options::<PowerUnit>());
+
+pub fn run(input: String, target: String) -> String { quantity::run::<PowerUnit>(input, target) }
+
+pub fn convert(input: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert::<PowerUnit>(input, target, locale)
+}
+
+pub fn convert_value(value: f64, unit: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert_value::<PowerUnit>(value, unit, target, locale)
+}
+
+/// An amount of power, stored in watts.
+pub type Power = Quantity<PowerUnit>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PowerUnit
+{
+    Watt,
+    Kilowatt,
+
+    /// Mechanical (imperial) horsepower, 550 ft·lbf/s
+    Horsepower,
+
+    /// Metric horsepower (PS, CV), 75 kgf·m/s
+    MetricHorsepower,
+}
+
+impl LinearUnit for PowerUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::Watt,
+        Self::Kilowatt,
+        Self::Horsepower,
+        Self::MetricHorsepower,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::Watt => 1.0,
+            Self::Kilowatt => 1e3,
+            Self::Horsepower => 745.6998715822702,
+            Self::MetricHorsepower => 735.49875,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Watt => "W",
+            Self::Kilowatt => "kW",
+            Self::Horsepower => "hp",
+            Self::MetricHorsepower => "PS",
+        }
+    }
+}
+
+impl FromStr for PowerUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "w" | "watt" | "watts" => Self::Watt,
+            "kw" | "kilowatt" | "kilowatts" => Self::Kilowatt,
+            "hp" | "bhp" | "hp(i)" | "horsepower" | "mechanical horsepower" => Self::Horsepower,
+            "ps" | "cv" | "hp(m)" | "metric horsepower" => Self::MetricHorsepower,
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for PowerUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_horsepower_variants()
+    {
+        assert_eq!(
+            run("100 hp".to_string(), "ps".to_string()),
+            "100.00 Mechanical horsepower [hp] -> 101.39 Metric horsepower [PS]"
+        );
+        assert_eq!(
+            run("100 kW".to_string(), "hp".to_string()),
+            "100.00 Kilowatt(s) [kW] -> 134.10 Mechanical horsepower [hp]"
+        );
+    }
+}
@@ -0,0 +1,428 @@
+use crate::{
+    currency::{self, CurrencyError, CurrencyType},
+    i18n::Locale,
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+    temperature::{self, ParseTempError, TemperatureUnit},
+    time::{self, ParseTimeError, TimeNotation},
+    timezone::{TimeZoneError, Zone},
+};
+use serde_derive::{Deserialize, Serialize};
+use serenity::model::id::{GuildId, UserId};
+use std::{collections::HashMap, fmt, fs, io, path::PathBuf, str::FromStr};
+use thiserror::Error;
+
+/// The `settings` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "settings",
+    "View or change the units your conversions default to.",
+)
+.details("View or change the units conversions default to when no target is given. Anything you haven't set falls back to the server's defaults. Leave every option out to see your preferences")
+.options(&[
+    OptionSpec::string("temperature", "Your preferred temperature scale.")
+        .optional()
+        .choices(temperature::choices),
+    OptionSpec::string("currency", "Your preferred currency.")
+        .optional()
+        .choices(currency::choices),
+    OptionSpec::string("notation", "Whether you prefer 12h or 24h time, or another notation.")
+        .optional()
+        .choices(time::choices),
+    OptionSpec::string("zone", "Your time zone (e.g. 'Europe/London', 'EST', 'Tokyo', 'UTC+5:30').")
+        .optional(),
+    OptionSpec::string("locale", "Your language and region (e.g. 'en-GB', 'de').").optional(),
+    OptionSpec::boolean("reset", "Clear your preferences before applying any given here."),
+    OptionSpec::boolean("server", "Change the server's defaults instead of yours. Needs the Manage Server permission.")
+        .details("Change the server's defaults, which apply to everyone who hasn't set their own, instead of yours. Needs the Manage Server permission"),
+]);
+
+/// Show `owner`'s preferences in `locale` after applying `changes`, which
+/// replace all of them if `reset` is set.
+pub fn run(
+    store: &mut dyn PreferenceStore,
+    owner: Owner,
+    changes: Preferences,
+    reset: bool,
+    locale: Locale,
+) -> String
+{
+    reply(store, owner, changes, reset, locale).to_string()
+}
+
+/// Like [`run`], but tells errors apart from the preferences.
+pub fn reply(
+    store: &mut dyn PreferenceStore,
+    owner: Owner,
+    changes: Preferences,
+    reset: bool,
+    locale: Locale,
+) -> Reply
+{
+    let heading = match owner
+    {
+        Owner::User(_) => locale.text("settings.user", &[]),
+        Owner::Guild(_) => locale.text("settings.guild", &[]),
+    };
+    match update(store, owner, changes, reset)
+    {
+        Ok(preferences) => Reply::Text(format!("{heading}:\n{}", preferences.describe(locale))),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+/// Apply `changes` to `owner`'s preferences, clearing them first if `reset` is
+/// set, and return the result.
+pub fn update(
+    store: &mut dyn PreferenceStore,
+    owner: Owner,
+    changes: Preferences,
+    reset: bool,
+) -> Result<Preferences, PreferencesError>
+{
+    let current = if reset
+    {
+        Preferences::default()
+    }
+    else
+    {
+        store.get(owner)?
+    };
+    let updated = changes.or(current);
+    store.set(owner, updated.clone())?;
+    Ok(updated)
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum PreferencesError
+{
+    Io(#[from] io::Error),
+    Json(#[from] serde_json::Error),
+    Temperature(#[from] ParseTempError),
+    Currency(#[from] CurrencyError),
+    Notation(#[from] ParseTimeError),
+    Zone(#[from] TimeZoneError),
+    InvalidLocale(String),
+}
+
+impl PreferencesError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        let storage =
+            |error: String| locale.text("error.preferences.storage", &[("error", &error)]);
+        match self
+        {
+            Self::Io(e) => storage(e.to_string()),
+            Self::Json(e) => storage(e.to_string()),
+            Self::Temperature(e) => e.text(locale),
+            Self::Currency(e) => e.text(locale),
+            Self::Notation(e) => e.text(locale),
+            Self::Zone(e) => e.text(locale),
+            Self::InvalidLocale(tag) =>
+            {
+                locale.text("error.preferences.invalid-locale", &[("locale", tag)])
+            }
+        }
+    }
+}
+
+/// The units someone's conversions default to when no target is given.
+/// Anything unset falls back to their guild's defaults, then to each
+/// conversion's own default.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preferences
+{
+    pub temperature: Option<TemperatureUnit>,
+    pub currency: Option<CurrencyType>,
+    pub notation: Option<TimeNotation>,
+
+    /// A time zone, written the way [`Zone`] shows it so it parses back
+    pub zone: Option<String>,
+
+    /// A language tag (e.g. 'en-GB')
+    pub locale: Option<String>,
+}
+
+impl Preferences
+{
+    /// The preferences one per line, labelled in `locale`.
+    pub fn describe(&self, locale: Locale) -> String
+    {
+        let show = |label, x: Option<String>| {
+            let value = x.unwrap_or_else(|| locale.text("settings.unset", &[]));
+            format!("{}: {value}", locale.text(label, &[]))
+        };
+        [
+            show(
+                "settings.temperature",
+                self.temperature.map(|x| x.name(locale)),
+            ),
+            show("settings.currency", self.currency.map(|x| x.name(locale))),
+            show("settings.notation", self.notation.map(|x| x.to_string())),
+            show("settings.zone", self.zone.clone()),
+            show("settings.locale", self.locale.clone()),
+        ]
+        .join("\n")
+    }
+
+    /// Parse preferences given as slash command options, leaving out any that
+    /// weren't given.
+    pub fn parse(
+        temperature: Option<String>,
+        currency: Option<String>,
+        notation: Option<String>,
+        zone: Option<String>,
+        locale: Option<String>,
+    ) -> Result<Self, PreferencesError>
+    {
+        Ok(Self {
+            temperature: temperature
+                .map(|x| TemperatureUnit::from_str(&x))
+                .transpose()?,
+            currency: currency.map(|x| CurrencyType::from_str(&x)).transpose()?,
+            notation: notation.map(|x| TimeNotation::from_str(&x)).transpose()?,
+            zone: zone
+                .map(|x| Zone::from_str(&x).map(|zone| zone.to_string()))
+                .transpose()?,
+            locale: locale.map(|x| parse_locale(&x)).transpose()?,
+        })
+    }
+
+    /// Fill in anything unset from `fallback`.
+    pub fn or(self, fallback: Self) -> Self
+    {
+        Self {
+            temperature: self.temperature.or(fallback.temperature),
+            currency: self.currency.or(fallback.currency),
+            notation: self.notation.or(fallback.notation),
+            zone: self.zone.or(fallback.zone),
+            locale: self.locale.or(fallback.locale),
+        }
+    }
+}
+
+impl fmt::Display for Preferences
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.describe(Locale::English))
+    }
+}
+
+/// Check a language tag (e.g. 'en-gb'), and write it the usual way ('en-GB').
+fn parse_locale(s: &str) -> Result<String, PreferencesError>
+{
+    let invalid = || PreferencesError::InvalidLocale(s.trim().to_string());
+    let mut parts = s.trim().split(['-', '_']);
+    let language = parts.next().unwrap_or_default();
+    if !(2..=3).contains(&language.len()) || !language.chars().all(|c| c.is_ascii_alphabetic())
+    {
+        return Err(invalid());
+    }
+
+    let mut locale = language.to_lowercase();
+    for part in parts
+    {
+        if !(2..=4).contains(&part.len()) || !part.chars().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(invalid());
+        }
+        locale.push('-');
+        // Regions are upper case (e.g. 'GB'), scripts are title case
+        // (e.g. 'Latn')
+        match part.len()
+        {
+            4 => locale.push_str(&(part[..1].to_uppercase() + &part[1..].to_lowercase())),
+            _ => locale.push_str(&part.to_uppercase()),
+        }
+    }
+    Ok(locale)
+}
+
+/// Whose preferences they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Owner
+{
+    User(UserId),
+
+    /// The defaults for everyone in a guild
+    Guild(GuildId),
+}
+
+/// Somewhere preferences are kept.
+pub trait PreferenceStore: fmt::Debug + Send
+{
+    /// The preferences of `owner`, which are all unset if they've never been
+    /// changed.
+    fn get(&self, owner: Owner) -> Result<Preferences, PreferencesError>;
+
+    fn set(&mut self, owner: Owner, preferences: Preferences) -> Result<(), PreferencesError>;
+}
+
+/// Preferences kept in memory, which are lost when the bot stops.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MemoryStore
+{
+    users: HashMap<u64, Preferences>,
+    guilds: HashMap<u64, Preferences>,
+}
+
+impl PreferenceStore for MemoryStore
+{
+    fn get(&self, owner: Owner) -> Result<Preferences, PreferencesError>
+    {
+        let preferences = match owner
+        {
+            Owner::User(id) => self.users.get(&id.0),
+            Owner::Guild(id) => self.guilds.get(&id.0),
+        };
+        Ok(preferences.cloned().unwrap_or_default())
+    }
+
+    fn set(&mut self, owner: Owner, preferences: Preferences) -> Result<(), PreferencesError>
+    {
+        let (map, id) = match owner
+        {
+            Owner::User(id) => (&mut self.users, id.0),
+            Owner::Guild(id) => (&mut self.guilds, id.0),
+        };
+        // Nobody is kept once they've nothing set
+        if preferences == Preferences::default()
+        {
+            map.remove(&id);
+        }
+        else
+        {
+            map.insert(id, preferences);
+        }
+        Ok(())
+    }
+}
+
+/// Preferences kept in a JSON file, which is rewritten whenever they change.
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonStore
+{
+    path: PathBuf,
+    store: MemoryStore,
+}
+
+impl JsonStore
+{
+    /// Open the preferences in the file at `path`, which is created when they
+    /// first change.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, PreferencesError>
+    {
+        let path = path.into();
+        let store = match fs::read_to_string(&path)
+        {
+            Ok(json) => serde_json::from_str(&json)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => MemoryStore::default(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(Self { path, store })
+    }
+}
+
+impl PreferenceStore for JsonStore
+{
+    fn get(&self, owner: Owner) -> Result<Preferences, PreferencesError> { self.store.get(owner) }
+
+    fn set(&mut self, owner: Owner, preferences: Preferences) -> Result<(), PreferencesError>
+    {
+        let mut store = self.store.clone();
+        store.set(owner, preferences)?;
+
+        // Write to another file first, so a crash can't leave the file
+        // half-written
+        let temporary = self.path.with_extension("tmp");
+        fs::write(&temporary, serde_json::to_string_pretty(&store)?)?;
+        fs::rename(&temporary, &self.path)?;
+        self.store = store;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn preferences(temperature: &str, zone: &str) -> Preferences
+    {
+        Preferences::parse(
+            Some(temperature.to_string()),
+            None,
+            None,
+            Some(zone.to_string()),
+            None,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_parse()
+    {
+        assert_eq!(
+            preferences("fahrenheit", "new york"),
+            Preferences {
+                temperature: Some(TemperatureUnit::Fahrenheit),
+                zone: Some("America/New_York".to_string()),
+                ..Default::default()
+            }
+        );
+        assert_eq!(parse_locale("en_gb").unwrap(), "en-GB");
+        assert_eq!(parse_locale("zh-hant-tw").unwrap(), "zh-Hant-TW");
+        assert!(parse_locale("english").is_err());
+        assert!(Preferences::parse(None, Some("doubloons".to_string()), None, None, None).is_err());
+    }
+
+    #[test]
+    fn test_update()
+    {
+        let mut store = MemoryStore::default();
+        let (user, guild) = (Owner::User(UserId(1)), Owner::Guild(GuildId(2)));
+        update(&mut store, user, preferences("F", "EST"), false).unwrap();
+        let changes = Preferences {
+            currency: Some(CurrencyType::Eur),
+            ..Default::default()
+        };
+        let updated = update(&mut store, user, changes.clone(), false).unwrap();
+        assert_eq!(updated.temperature, Some(TemperatureUnit::Fahrenheit));
+        assert_eq!(updated.currency, Some(CurrencyType::Eur));
+        assert_eq!(store.get(guild).unwrap(), Preferences::default());
+
+        let updated = update(&mut store, user, changes, true).unwrap();
+        assert_eq!(updated.temperature, None);
+        assert_eq!(
+            run(&mut store, user, Preferences::default(), true, Locale::English),
+            "Your preferences:\nTemperature: not set\nCurrency: not set\nTime notation: not set\nTime zone: not set\nLocale: not set"
+        );
+        assert!(run(
+            &mut store,
+            guild,
+            Preferences::default(),
+            false,
+            Locale::German
+        )
+        .starts_with("Standards dieses Servers:\nTemperatur: nicht festgelegt\n"));
+    }
+
+    #[test]
+    fn test_json_store()
+    {
+        let path =
+            std::env::temp_dir().join(format!("bor-preferences-{}.json", std::process::id()));
+        let owner = Owner::Guild(GuildId(3));
+        let mut store = JsonStore::open(&path).unwrap();
+        store.set(owner, preferences("C", "Tokyo")).unwrap();
+
+        let reopened = JsonStore::open(&path).unwrap();
+        assert_eq!(reopened.get(owner).unwrap(), preferences("C", "Tokyo"));
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,159 @@
+use crate::{
+    i18n::Locale,
+    quantity::{self, LinearUnit, ParseUnitError, Quantity},
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+};
+use std::{fmt, str::FromStr};
+
+/// The `pressure` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "pressure",
+    "Convert from one pressure unit to another. Supports Pa, kPa, bar, psi, atm, mmHg, and inHg.",
+)
+.options(&[
+    OptionSpec::string("value", "Original value (e.g. '32 psi', '101.3 kPa')."),
+    OptionSpec::string(
+        "target",
+        "The unit to target. (e.g 'bar', 'inHg' [inches of mercury]).",
+    ),
+]);
+
+/// The `pressure` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "pressure",
+    "Convert a number from one pressure unit to another.",
+)
+.options(&quantity::typed_options::<PressureUnit>());
+
+pub fn run(input: String, target: String) -> String { quantity::run::<PressureUnit>(input, target) }
+
+pub fn convert(input: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert::<PressureUnit>(input, target, locale)
+}
+
+pub fn convert_value(value: f64, unit: String, target: String, locale: Locale) -> Reply
+{
+    quantity::convert_value::<PressureUnit>(value, unit, target, locale)
+}
+
+/// A pressure, stored in pascals.
+pub type Pressure = Quantity<PressureUnit>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PressureUnit
+{
+    Pascal,
+    Kilopascal,
+    Bar,
+
+    /// Pounds per square inch
+    Psi,
+
+    /// Standard atmosphere
+    Atmosphere,
+
+    /// Millimetres of mercury
+    MillimetreOfMercury,
+
+    /// Inches of mercury
+    InchOfMercury,
+}
+
+impl LinearUnit for PressureUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::Pascal,
+        Self::Kilopascal,
+        Self::Bar,
+        Self::Psi,
+        Self::Atmosphere,
+        Self::MillimetreOfMercury,
+        Self::InchOfMercury,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::Pascal => 1.0,
+            Self::Kilopascal => 1e3,
+            Self::Bar => 1e5,
+            Self::Psi => 6894.757293168,
+            Self::Atmosphere => 101325.0,
+            Self::MillimetreOfMercury => 133.322387415,
+            Self::InchOfMercury => 3386.389,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Pascal => "Pa",
+            Self::Kilopascal => "kPa",
+            Self::Bar => "bar",
+            Self::Psi => "psi",
+            Self::Atmosphere => "atm",
+            Self::MillimetreOfMercury => "mmHg",
+            Self::InchOfMercury => "inHg",
+        }
+    }
+}
+
+impl FromStr for PressureUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "pa" | "pascal" | "pascals" => Self::Pascal,
+            "kpa" | "kilopascal" | "kilopascals" => Self::Kilopascal,
+            "bar" | "bars" => Self::Bar,
+            "psi" => Self::Psi,
+            "atm" | "atmosphere" | "atmospheres" => Self::Atmosphere,
+            "mmhg" | "mm hg" => Self::MillimetreOfMercury,
+            "inhg" | "in hg" => Self::InchOfMercury,
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for PressureUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_psi_to_bar()
+    {
+        assert_eq!(
+            run("32 psi".to_string(), "bar".to_string()),
+            "32.00 Pound(s) per square inch [psi] -> 2.21 Bar [bar]"
+        );
+    }
+
+    #[test]
+    fn test_atmosphere_to_mercury()
+    {
+        let mut pressure = Pressure::from_str("1 atm").unwrap();
+        pressure.into_unit(PressureUnit::InchOfMercury);
+        assert_eq!(pressure.to_string(), "29.92 Inch(es) of mercury [inHg]");
+        pressure.into_unit(PressureUnit::MillimetreOfMercury);
+        assert_eq!(
+            pressure.to_string(),
+            "760.00 Millimetre(s) of mercury [mmHg]"
+        );
+    }
+}
@@ -0,0 +1,276 @@
+use crate::{
+    i18n::Locale,
+    reply::{Conversion, Reply},
+    spec::OptionSpec,
+};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// A unit which is a fixed multiple of its quantity's base unit (e.g. a
+/// hectare is 10,000 square metres).
+pub trait LinearUnit: Copy + fmt::Display + FromStr<Err = ParseUnitError> + 'static
+{
+    /// The number of base units in one of this unit.
+    fn factor(&self) -> f64;
+
+    /// The short form of the unit (e.g. 'kWh', 'ft²').
+    fn symbol(&self) -> &'static str;
+
+    /// The unit's name in `locale`, with its symbol (e.g. 'Kilowatt(s) [kW]').
+    fn name(&self, locale: Locale) -> String
+    {
+        let name = locale.text(&format!("unit.{}", self.symbol()), &[]);
+        format!("{name} [{}]", self.symbol())
+    }
+
+    /// Every unit, in the order they're offered as choices.
+    const ALL: &'static [Self];
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum ParseUnitError
+{
+    InvalidUnit(String),
+    InvalidNumber(String),
+}
+
+impl ParseUnitError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::InvalidUnit(unit) => locale.text("error.invalid-unit", &[("unit", unit)]),
+            Self::InvalidNumber(number) =>
+            {
+                locale.text("error.invalid-number", &[("number", number)])
+            }
+        }
+    }
+}
+
+/// Convert `input` (e.g. '3 acres') into the `target` unit (e.g. 'ha').
+pub fn run<U: LinearUnit>(input: String, target: String) -> String
+{
+    convert::<U>(input, target, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+pub fn convert<U: LinearUnit>(input: String, target: String, locale: Locale) -> Reply
+{
+    match Quantity::<U>::from_str(&input)
+    {
+        Ok(quantity) => convert_quantity(quantity, &target, locale),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+/// Like [`convert`], but with the number and its unit given separately (e.g.
+/// from typed slash command options).
+pub fn convert_value<U: LinearUnit>(
+    value: f64,
+    unit: String,
+    target: String,
+    locale: Locale,
+) -> Reply
+{
+    match U::from_str(&unit)
+    {
+        Ok(unit) => convert_quantity(Quantity::new(value, unit), &target, locale),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+fn convert_quantity<U: LinearUnit>(mut quantity: Quantity<U>, target: &str, locale: Locale)
+    -> Reply
+{
+    let original = quantity;
+
+    match U::from_str(target)
+    {
+        Ok(unit) => quantity.into_unit(unit),
+        Err(e) => return Reply::Error(e.text(locale)),
+    }
+
+    Reply::Conversion(Conversion {
+        input: original.text(locale),
+        output: quantity.text(locale),
+        units: Some((original.unit.name(locale), quantity.unit.name(locale))),
+        ..Default::default()
+    })
+}
+
+/// Each of `U`'s units as a slash command choice, named in full with the
+/// symbol as the value.
+pub fn choices<U: LinearUnit>() -> Vec<(String, String)>
+{
+    U::ALL
+        .iter()
+        .map(|unit| (unit.to_string(), unit.symbol().to_string()))
+        .collect()
+}
+
+/// Options for converting a number from one of `U`'s units into another, for
+/// the typed command layout.
+pub const fn typed_options<U: LinearUnit>() -> [OptionSpec; 3]
+{
+    [
+        OptionSpec::number("value", "The number to convert (e.g. 3.5)."),
+        OptionSpec::string("unit", "The unit the number is in.").choices(choices::<U>),
+        OptionSpec::string("target", "The unit to convert to.").choices(choices::<U>),
+    ]
+}
+
+/// Split a quantity like '1.5e3 kWh' into its number and unit.
+pub(crate) fn split_quantity(s: &str) -> Result<(f64, &str), ParseUnitError>
+{
+    let s = s.trim();
+    // The longest leading number, so exponents ('1e3') aren't mistaken for
+    // units and units starting with 'e' ('eV') aren't eaten by the number.
+    let split = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()))
+        .rev()
+        .find(|&i| {
+            let number = s[..i].trim();
+            number.starts_with(|c: char| c.is_ascii_digit() || "+-.".contains(c))
+                && number.parse::<f64>().is_ok()
+        });
+
+    match split
+    {
+        Some(i) => Ok((s[..i].trim().parse().unwrap(), s[i..].trim())),
+        None =>
+        {
+            let end = s.find(char::is_alphabetic).unwrap_or(s.len());
+            Err(ParseUnitError::InvalidNumber(s[..end].trim().to_string()))
+        }
+    }
+}
+
+/// Format a value with two decimal places, falling back to scientific
+/// notation when that would hide it (e.g. electronvolts in joules).
+pub(crate) fn format_value(value: f64) -> String
+{
+    if value != 0.0 && (value.abs() < 0.01 || value.abs() >= 1e12)
+    {
+        format!("{value:.3e}")
+    }
+    else
+    {
+        format!("{value:.2}")
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// A value of some linear unit. The value is stored in the base unit.
+pub struct Quantity<U>
+{
+    base: f64,
+    unit: U,
+}
+
+impl<U: LinearUnit> Quantity<U>
+{
+    pub fn new(value: f64, unit: U) -> Self
+    {
+        Self {
+            base: value * unit.factor(),
+            unit,
+        }
+    }
+
+    pub fn into_unit(&mut self, unit: U) { self.unit = unit; }
+
+    pub fn unit(&self) -> U { self.unit }
+
+    /// The value in its current unit.
+    pub fn value(&self) -> f64 { self.base / self.unit.factor() }
+
+    /// The value in the base unit of the quantity.
+    pub fn base_value(&self) -> f64 { self.base }
+
+    /// The quantity as text in `locale` (e.g. '3.00 Acre(s) [ac]').
+    pub fn text(&self, locale: Locale) -> String
+    {
+        format!("{} {}", format_value(self.value()), self.unit.name(locale))
+    }
+}
+
+impl<U: LinearUnit> FromStr for Quantity<U>
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (value, unit) = split_quantity(s)?;
+        Ok(Self::new(value, unit.parse()?))
+    }
+}
+
+impl<U: LinearUnit> fmt::Display for Quantity<U>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_split_quantity()
+    {
+        assert_eq!(split_quantity("5 eV").unwrap(), (5.0, "eV"));
+        assert_eq!(split_quantity("1.5e3kWh").unwrap(), (1500.0, "kWh"));
+        assert_eq!(split_quantity("-2.5 psi").unwrap(), (-2.5, "psi"));
+        assert!(split_quantity("inf psi").is_err());
+        assert!(split_quantity("psi").is_err());
+    }
+
+    fn assert_symbols_parse<U: LinearUnit + PartialEq + fmt::Debug>()
+    {
+        for unit in U::ALL
+        {
+            assert_eq!(U::from_str(unit.symbol()).ok(), Some(*unit));
+        }
+    }
+
+    #[test]
+    fn test_choices()
+    {
+        // Choices send the symbol, so it has to parse back into the unit
+        assert_symbols_parse::<crate::area::AreaUnit>();
+        assert_symbols_parse::<crate::energy::EnergyUnit>();
+        assert_symbols_parse::<crate::power::PowerUnit>();
+        assert_symbols_parse::<crate::pressure::PressureUnit>();
+        assert_symbols_parse::<crate::length::LengthUnit>();
+        assert_symbols_parse::<crate::volume::VolumeUnit>();
+        assert_symbols_parse::<crate::duration::DurationUnit>();
+
+        assert_eq!(
+            convert_value::<crate::power::PowerUnit>(
+                150.0,
+                "hp".to_string(),
+                "kW".to_string(),
+                Locale::English
+            )
+            .to_string(),
+            "150.00 Mechanical horsepower [hp] -> 111.85 Kilowatt(s) [kW]"
+        );
+    }
+
+    #[test]
+    fn test_format_value()
+    {
+        assert_eq!(format_value(12.345), "12.35");
+        assert_eq!(format_value(0.0), "0.00");
+        assert_eq!(format_value(1.602176634e-19), "1.602e-19");
+    }
+}
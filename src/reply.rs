@@ -0,0 +1,215 @@
+use crate::{
+    i18n::Locale,
+    timestamp::{Timestamp, TimestampStyle},
+};
+use chrono::{DateTime, Utc};
+use serenity::{builder::CreateEmbed, utils::Colour};
+use std::fmt;
+
+/// The result of a conversion, before it's shown.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Conversion
+{
+    pub input: String,
+    pub output: String,
+
+    /// The units converted from and to (e.g. 'acres' and 'hectares')
+    pub units: Option<(String, String)>,
+
+    /// How many of the output unit one of the input unit is worth, for
+    /// conversions which change over time (e.g. exchange rates)
+    pub rate: Option<f64>,
+
+    /// When `rate` was last updated
+    pub rate_time: Option<DateTime<Utc>>,
+
+    /// Extra information about the result (e.g. what 'MiB' means)
+    pub notes: Vec<String>,
+    pub warnings: Vec<String>,
+
+    /// Where the data behind the conversion came from
+    pub source: Option<&'static str>,
+}
+
+impl Conversion
+{
+    /// The conversion as plain text, with warnings in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        let mut text = format!("{} -> {}", self.input, self.output);
+        for note in &self.notes
+        {
+            text += &format!("\n{note}");
+        }
+        for warning in &self.warnings
+        {
+            text += "\n";
+            text += &locale.text("reply.warning", &[("warning", warning)]);
+        }
+        text
+    }
+}
+
+impl fmt::Display for Conversion
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+/// What a subcommand has to say, which can be shown as plain text or as an
+/// embed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reply
+{
+    Conversion(Conversion),
+
+    /// Anything without the structure of a conversion (e.g. a list of
+    /// timestamps)
+    Text(String),
+
+    /// Why the subcommand failed
+    Error(String),
+}
+
+impl Reply
+{
+    /// The reply as plain text, labelled in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::Conversion(conversion) => conversion.text(locale),
+            Self::Text(text) => text.clone(),
+            Self::Error(e) => locale.text("reply.error-prefix", &[("error", e)]),
+        }
+    }
+
+    /// Fill in an embed showing the reply, labelled in `locale` and coloured
+    /// red for errors.
+    pub fn embed<'a>(&self, embed: &'a mut CreateEmbed, locale: Locale) -> &'a mut CreateEmbed
+    {
+        let label = |key| locale.text(key, &[]);
+        match self
+        {
+            Self::Conversion(conversion) =>
+            {
+                embed
+                    .colour(Colour::DARK_GREEN)
+                    .field(label("reply.input"), &conversion.input, true)
+                    .field(label("reply.output"), &conversion.output, true);
+                if let Some((from, to)) = &conversion.units
+                {
+                    embed.field(label("reply.units"), format!("{from} -> {to}"), false);
+                }
+                if let Some(rate) = conversion.rate
+                {
+                    let rate = match conversion.rate_time
+                    {
+                        Some(when) => locale.text(
+                            "reply.rate-updated",
+                            &[
+                                ("rate", &format!("{rate:.6}")),
+                                (
+                                    "when",
+                                    &Timestamp(when.timestamp()).tag(TimestampStyle::Relative),
+                                ),
+                            ],
+                        ),
+                        None => format!("{rate:.6}"),
+                    };
+                    embed.field(label("reply.rate"), rate, false);
+                }
+                if !conversion.notes.is_empty()
+                {
+                    embed.description(conversion.notes.join("\n"));
+                }
+                if !conversion.warnings.is_empty()
+                {
+                    embed.field(
+                        label("reply.warnings"),
+                        conversion.warnings.join("\n"),
+                        false,
+                    );
+                }
+                if let Some(source) = conversion.source
+                {
+                    embed.footer(|footer| {
+                        footer.text(locale.text("reply.source", &[("source", source)]))
+                    });
+                }
+                embed
+            }
+            Self::Text(text) => embed.colour(Colour::BLURPLE).description(text),
+            Self::Error(e) => embed
+                .colour(Colour::RED)
+                .title(label("reply.error"))
+                .description(e),
+        }
+    }
+}
+
+impl fmt::Display for Reply
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_plain_text()
+    {
+        let conversion = Conversion {
+            input: "1.00 GiB".to_string(),
+            output: "1073.74 MB".to_string(),
+            notes: vec!["GiB: gibibyte (1024³ bytes)".to_string()],
+            ..Default::default()
+        };
+        assert_eq!(
+            Reply::Conversion(conversion).to_string(),
+            "1.00 GiB -> 1073.74 MB\nGiB: gibibyte (1024³ bytes)"
+        );
+        assert_eq!(
+            Reply::Error("Invalid unit".to_string()).to_string(),
+            "Error: Invalid unit"
+        );
+        assert_eq!(
+            Reply::Text("Heat index: 30.00°C".to_string()).to_string(),
+            "Heat index: 30.00°C"
+        );
+    }
+
+    #[test]
+    fn test_embed()
+    {
+        let conversion = Conversion {
+            input: "45.00 USD".to_string(),
+            output: "41.94 Euro(s) [EUR]".to_string(),
+            rate: Some(0.932001),
+            source: Some("currencyapi.com"),
+            ..Default::default()
+        };
+        let mut embed = CreateEmbed::default();
+        let reply = Reply::Conversion(conversion);
+        reply.embed(&mut embed, Locale::English);
+        assert_eq!(embed.0["color"], Colour::DARK_GREEN.0);
+        assert_eq!(embed.0["fields"].as_array().unwrap().len(), 3);
+        assert_eq!(embed.0["footer"]["text"], "Data from currencyapi.com");
+
+        let mut embed = CreateEmbed::default();
+        reply.embed(&mut embed, Locale::German);
+        assert_eq!(embed.0["fields"][0]["name"], "Eingabe");
+        assert_eq!(embed.0["footer"]["text"], "Daten von currencyapi.com");
+
+        let mut embed = CreateEmbed::default();
+        Reply::Error("Invalid unit".to_string()).embed(&mut embed, Locale::English);
+        assert_eq!(embed.0["color"], Colour::RED.0);
+    }
+}
@@ -0,0 +1,224 @@
+use crate::{
+    currency::{Currency, CurrencyConverter, CurrencyType},
+    i18n::Locale,
+    preferences::Preferences,
+    reply::{Conversion, Reply},
+    temperature::{self, Temperature},
+    time::Time,
+};
+
+/// The most words something convertible can take up (e.g. '10:30 pm').
+const MAX_WORDS: usize = 2;
+
+/// Punctuation around words which isn't part of what's written (e.g. the
+/// comma in '30°C,'). Full stops are only trimmed from the end, so '.5' stays.
+const PUNCTUATION: &[char] = &[
+    ',', ';', ':', '!', '?', '(', ')', '[', ']', '"', '\'', '“', '”', '*', '_', '~', '`',
+];
+
+/// Something convertible found in a piece of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Found
+{
+    Temperature(Temperature),
+
+    /// An amount of money, which isn't converted until exchange rates are
+    /// needed
+    Currency(f64, CurrencyType),
+
+    Time(Time),
+}
+
+impl Found
+{
+    /// Parse `s` as whichever kind of quantity it's written as, if any.
+    fn parse(s: &str) -> Option<Self>
+    {
+        Temperature::scan(s)
+            .map(Self::Temperature)
+            .or_else(|| Currency::scan(s).map(|(value, currency)| Self::Currency(value, currency)))
+            .or_else(|| Time::scan(s).map(Self::Time))
+    }
+
+    /// Convert to the preferred units, as a line like '30.00 Celsius -> 86.00
+    /// Fahrenheit' in `locale`. Anything without a preference is converted to
+    /// the other common unit (e.g. Celsius to Fahrenheit and back, 24 hour time
+    /// to 12 hour time and back).
+    pub fn convert(
+        self,
+        preferred: &Preferences,
+        converter: CurrencyConverter,
+        locale: Locale,
+    ) -> (Reply, CurrencyConverter)
+    {
+        let (input, output) = match self
+        {
+            Self::Temperature(temp) =>
+            {
+                return (
+                    temperature::convert_temperature(temp, preferred.temperature, locale),
+                    converter,
+                );
+            }
+            Self::Currency(value, currency) =>
+            {
+                let target = preferred.currency.unwrap_or_else(|| currency.counterpart());
+                return match Currency::new(value, currency, converter.clone())
+                {
+                    Ok(mut amount) =>
+                    {
+                        let original = amount.text(locale);
+                        amount.into_currency(target);
+                        let conversion = Conversion {
+                            input: original,
+                            output: amount.text(locale),
+                            source: Some("currencyapi.com"),
+                            ..Default::default()
+                        };
+                        (Reply::Conversion(conversion), amount.get_converter())
+                    }
+                    Err(e) => (Reply::Error(e.text(locale)), converter),
+                };
+            }
+            Self::Time(time) =>
+            {
+                let mut converted = time;
+                match preferred.notation
+                {
+                    Some(kind) => converted.to_notation(kind),
+                    None => converted.to_opposite(),
+                };
+                (time.to_string(), converted.to_string())
+            }
+        };
+        let conversion = Conversion {
+            input,
+            output,
+            ..Default::default()
+        };
+        (Reply::Conversion(conversion), converter)
+    }
+}
+
+/// Find every temperature, amount of money and time in `text`, in the order
+/// they're written.
+pub fn scan(text: &str) -> Vec<Found>
+{
+    let words: Vec<&str> = text
+        .split_whitespace()
+        .map(|word| {
+            word.trim_start_matches(PUNCTUATION)
+                .trim_end_matches(|c| PUNCTUATION.contains(&c) || c == '.')
+        })
+        .filter(|word| !word.is_empty())
+        .collect();
+
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i < words.len()
+    {
+        // The most words that make sense together, so '10:30 pm' isn't read
+        // as '10:30'
+        let next = (1..=MAX_WORDS.min(words.len() - i))
+            .rev()
+            .find_map(|n| Some((n, Found::parse(&words[i..i + n].join(" "))?)));
+        match next
+        {
+            Some((n, x)) =>
+            {
+                found.push(x);
+                i += n;
+            }
+            None => i += 1,
+        }
+    }
+    found
+}
+
+/// Convert everything found in `text` to the preferred units, one per line.
+pub fn run(
+    converter: CurrencyConverter,
+    text: String,
+    preferred: &Preferences,
+) -> (String, CurrencyConverter)
+{
+    let (reply, converter) = convert(converter, text, preferred, Locale::English);
+    (reply.to_string(), converter)
+}
+
+/// Like [`run`], but tells errors apart from the conversions, written in
+/// `locale`.
+pub fn convert(
+    mut converter: CurrencyConverter,
+    text: String,
+    preferred: &Preferences,
+    locale: Locale,
+) -> (Reply, CurrencyConverter)
+{
+    let found = scan(&text);
+    if found.is_empty()
+    {
+        return (
+            Reply::Error(locale.text("error.scan.nothing-found", &[])),
+            converter,
+        );
+    }
+
+    let mut lines = Vec::new();
+    for x in found
+    {
+        let reply;
+        (reply, converter) = x.convert(preferred, converter, locale);
+        lines.push(reply.text(locale));
+    }
+    (Reply::Text(lines.join("\n")), converter)
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{currency::tests::converter, temperature::TemperatureUnit};
+    use std::str::FromStr;
+
+    #[test]
+    fn test_scan()
+    {
+        assert_eq!(
+            scan("It's 30°C out, so meet at 5 pm (bring $20, or 15 quid)."),
+            [
+                Found::Temperature(Temperature::from_str("30C").unwrap()),
+                Found::Time(Time::from_str("5pm").unwrap()),
+                Found::Currency(20.0, CurrencyType::Usd),
+                Found::Currency(15.0, CurrencyType::Gbp),
+            ]
+        );
+        // Numbers on their own, or followed by ordinary words, aren't
+        // converted
+        assert!(scan("I have 5 cats and 2 k of rice, see chapter 3").is_empty());
+    }
+
+    #[test]
+    fn test_run()
+    {
+        let (reply, _) = run(
+            converter(),
+            "Forecast: 86 F at 14:30".to_string(),
+            &Preferences::default(),
+        );
+        assert_eq!(
+            reply,
+            "86.00 Fahrenheit -> 30.00 Celsius\n14:30:00 -> 02:30:00 PM"
+        );
+
+        let preferred = Preferences {
+            temperature: Some(TemperatureUnit::Kelvin),
+            ..Default::default()
+        };
+        let (reply, _) = run(converter(), "0°C".to_string(), &preferred);
+        assert_eq!(reply, "0.00 Celsius -> 273.15 Kelvin");
+
+        let (reply, _) = run(converter(), "nothing here".to_string(), &preferred);
+        assert!(reply.starts_with("Error: "));
+    }
+}
@@ -0,0 +1,471 @@
+use crate::i18n::Locale;
+use serenity::{
+    builder::{CreateApplicationCommand, CreateApplicationCommandOption},
+    model::prelude::command::CommandOptionType,
+};
+
+/// The most characters Discord allows in a command or option description.
+pub const MAX_DESCRIPTION: usize = 100;
+
+/// The most choices Discord allows for an option, or suggests at once.
+/// Options with more choices are autocompleted instead.
+pub const MAX_CHOICES: usize = 25;
+
+/// Choices for an option, as names shown to the user and the values sent.
+pub type Choices = fn() -> Vec<(String, String)>;
+
+/// The translations of a name, as Discord locale codes and names.
+fn names(name: &str) -> Vec<(&'static str, &'static str)> { translations(&format!("name.{name}")) }
+
+/// The translations of `key`, as Discord locale codes and text.
+fn translations(key: &str) -> Vec<(&'static str, &'static str)>
+{
+    Locale::ALL
+        .iter()
+        .filter_map(|locale| Some((locale.discord_code(), locale.translate(key)?)))
+        .collect()
+}
+
+/// The kinds of value an option takes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionKind
+{
+    String,
+    Boolean,
+    Number,
+}
+
+impl OptionKind
+{
+    fn command_option_type(self) -> CommandOptionType
+    {
+        match self
+        {
+            Self::String => CommandOptionType::String,
+            Self::Boolean => CommandOptionType::Boolean,
+            Self::Number => CommandOptionType::Number,
+        }
+    }
+
+    /// The name shown in the help message.
+    fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::String => "String",
+            Self::Boolean => "Boolean",
+            Self::Number => "Number",
+        }
+    }
+}
+
+/// An option to a subcommand.
+#[derive(Debug, Clone, Copy)]
+pub struct OptionSpec
+{
+    pub name: &'static str,
+    pub kind: OptionKind,
+    pub required: bool,
+
+    /// Shown in Discord, so it's at most [`MAX_DESCRIPTION`] characters
+    pub description: &'static str,
+
+    /// Shown in the help message instead of the description, when there's
+    /// more worth saying than fits in Discord
+    pub details: Option<&'static str>,
+
+    /// The values the option can take, if it's limited to a list
+    pub choices: Option<Choices>,
+}
+
+impl OptionSpec
+{
+    /// A required string option.
+    pub const fn string(name: &'static str, description: &'static str) -> Self
+    {
+        Self {
+            name,
+            kind: OptionKind::String,
+            required: true,
+            description,
+            details: None,
+            choices: None,
+        }
+    }
+
+    /// An optional boolean option.
+    pub const fn boolean(name: &'static str, description: &'static str) -> Self
+    {
+        Self {
+            name,
+            kind: OptionKind::Boolean,
+            required: false,
+            description,
+            details: None,
+            choices: None,
+        }
+    }
+
+    /// A required number option.
+    pub const fn number(name: &'static str, description: &'static str) -> Self
+    {
+        Self {
+            name,
+            kind: OptionKind::Number,
+            required: true,
+            description,
+            details: None,
+            choices: None,
+        }
+    }
+
+    pub const fn optional(mut self) -> Self
+    {
+        self.required = false;
+        self
+    }
+
+    pub const fn details(mut self, details: &'static str) -> Self
+    {
+        self.details = Some(details);
+        self
+    }
+
+    pub const fn choices(mut self, choices: Choices) -> Self
+    {
+        self.choices = Some(choices);
+        self
+    }
+
+    /// The choices whose name or value contains `partial`, as many as Discord
+    /// will show.
+    pub fn suggest(&self, partial: &str) -> Vec<(String, String)>
+    {
+        let partial = partial.trim().to_lowercase();
+        self.choices
+            .map(|choices| choices())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(name, value)| {
+                name.to_lowercase().contains(&partial) || value.to_lowercase().contains(&partial)
+            })
+            .take(MAX_CHOICES)
+            .collect()
+    }
+
+    /// Fill in the option, where `path` is its subcommand's (e.g.
+    /// 'conversions.temperature'), for finding translations.
+    fn create<'a>(
+        &self,
+        option: &'a mut CreateApplicationCommandOption,
+        path: &str,
+    ) -> &'a mut CreateApplicationCommandOption
+    {
+        option
+            .name(self.name)
+            .description(self.description)
+            .kind(self.kind.command_option_type())
+            .required(self.required);
+        for (locale, name) in names(self.name)
+        {
+            option.name_localized(locale, name);
+        }
+        for (locale, description) in translations(&format!("{path}.{}", self.name))
+        {
+            option.description_localized(locale, description);
+        }
+
+        // Long lists are suggested as the user types instead
+        let choices = self.choices.map(|choices| choices()).unwrap_or_default();
+        if choices.len() > MAX_CHOICES
+        {
+            option.set_autocomplete(true);
+        }
+        else
+        {
+            for (name, value) in choices
+            {
+                let locales = translations(&format!("choice.{value}"));
+                option.add_string_choice_localized(name, value, locales);
+            }
+        }
+        option
+    }
+
+    fn help(&self, path: &str, locale: Locale) -> help::HelpMessageOption
+    {
+        let description = locale
+            .translate(&format!("{path}.{}", self.name))
+            .unwrap_or(self.details.unwrap_or(self.description));
+        help::HelpMessageOption::new()
+            .name(
+                locale
+                    .translate(&format!("name.{}", self.name))
+                    .unwrap_or(self.name),
+            )
+            .kind(self.kind.name())
+            .description(description)
+            .required(self.required)
+            .clone()
+    }
+}
+
+/// A subcommand, and the options it takes.
+#[derive(Debug, Clone, Copy)]
+pub struct SubcommandSpec
+{
+    pub name: &'static str,
+
+    /// Shown in Discord, so it's at most [`MAX_DESCRIPTION`] characters
+    pub description: &'static str,
+
+    /// Shown in the help message instead of the description, when there's
+    /// more worth saying than fits in Discord
+    pub details: Option<&'static str>,
+
+    pub options: &'static [OptionSpec],
+}
+
+impl SubcommandSpec
+{
+    pub const fn new(name: &'static str, description: &'static str) -> Self
+    {
+        Self {
+            name,
+            description,
+            details: None,
+            options: &[],
+        }
+    }
+
+    pub const fn details(mut self, details: &'static str) -> Self
+    {
+        self.details = Some(details);
+        self
+    }
+
+    pub const fn options(mut self, options: &'static [OptionSpec]) -> Self
+    {
+        self.options = options;
+        self
+    }
+
+    /// Fill in the subcommand, where `path` is its command's name.
+    fn create<'a>(
+        &self,
+        option: &'a mut CreateApplicationCommandOption,
+        path: &str,
+    ) -> &'a mut CreateApplicationCommandOption
+    {
+        let path = format!("{path}.{}", self.name);
+        option
+            .name(self.name)
+            .kind(CommandOptionType::SubCommand)
+            .description(self.description);
+        for (locale, name) in names(self.name)
+        {
+            option.name_localized(locale, name);
+        }
+        for (locale, description) in translations(&path)
+        {
+            option.description_localized(locale, description);
+        }
+        for spec in self.options
+        {
+            option.create_sub_option(|option| spec.create(option, &path));
+        }
+        option
+    }
+
+    /// The option called `name`, if there is one.
+    pub fn option(&self, name: &str) -> Option<&'static OptionSpec>
+    {
+        self.options.iter().find(|spec| spec.name == name)
+    }
+
+    fn help(&self, path: &str, locale: Locale) -> help::HelpMessage
+    {
+        let path = format!("{path}.{}", self.name);
+        let mut message = help::HelpMessage::new();
+        message
+            .name(
+                locale
+                    .translate(&format!("name.{}", self.name))
+                    .unwrap_or(self.name),
+            )
+            .description(
+                locale
+                    .translate(&path)
+                    .unwrap_or(self.details.unwrap_or(self.description)),
+            );
+        for spec in self.options
+        {
+            message.add_option(spec.help(&path, locale));
+        }
+        message
+    }
+}
+
+/// A slash command made of subcommands, from which both its registration and
+/// its help message are made.
+#[derive(Debug, Clone, Copy)]
+pub struct CommandSpec
+{
+    pub name: &'static str,
+    pub description: &'static str,
+    pub subcommands: &'static [SubcommandSpec],
+}
+
+impl CommandSpec
+{
+    pub fn register<'a>(
+        &self,
+        command: &'a mut CreateApplicationCommand,
+    ) -> &'a mut CreateApplicationCommand
+    {
+        command
+            .name(self.name)
+            .description(self.description)
+            .dm_permission(true);
+        for (locale, name) in names(self.name)
+        {
+            command.name_localized(locale, name);
+        }
+        for (locale, description) in translations(self.name)
+        {
+            command.description_localized(locale, description);
+        }
+        for spec in self.subcommands
+        {
+            command.create_option(|option| spec.create(option, self.name));
+        }
+        command
+    }
+
+    /// The help message, in `locale` where it's been translated.
+    pub fn help(&self, locale: Locale) -> help::HelpMessage
+    {
+        let mut message = help::HelpMessage::new();
+        message
+            .name(
+                locale
+                    .translate(&format!("name.{}", self.name))
+                    .unwrap_or(self.name),
+            )
+            .description(locale.translate(self.name).unwrap_or(self.description));
+        for spec in self.subcommands
+        {
+            message.add_subcommand(spec.help(self.name, locale));
+        }
+        message
+    }
+
+    /// The subcommand called `name`, if there is one.
+    pub fn subcommand(&self, name: &str) -> Option<&'static SubcommandSpec>
+    {
+        self.subcommands.iter().find(|spec| spec.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use crate::{COMMAND, TYPED_COMMAND};
+
+    #[test]
+    fn test_discord_limits()
+    {
+        for command in [COMMAND, TYPED_COMMAND]
+        {
+            // Discord allows at most 25 options to a command, and they need
+            // unique names
+            assert!(command.subcommands.len() <= 25);
+            for spec in command.subcommands
+            {
+                assert!(
+                    std::ptr::eq(command.subcommand(spec.name).unwrap(), spec),
+                    "'{}' is used twice",
+                    spec.name
+                );
+                assert!(
+                    spec.description.chars().count() <= MAX_DESCRIPTION,
+                    "'{}' has too long a description",
+                    spec.name
+                );
+                for option in spec.options
+                {
+                    assert!(
+                        option.description.chars().count() <= MAX_DESCRIPTION,
+                        "'{} {}' has too long a description",
+                        spec.name,
+                        option.name
+                    );
+                    for (name, value) in option.choices.map(|x| x()).unwrap_or_default()
+                    {
+                        assert!(name.chars().count() <= 100 && value.chars().count() <= 100);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_translations()
+    {
+        // Discord only allows lowercase names without spaces for slash commands
+        // and their options
+        let valid_name = |name: &str| {
+            name.chars().count() <= 32
+                && name
+                    .chars()
+                    .all(|c| !c.is_uppercase() && !c.is_whitespace())
+        };
+        for locale in Locale::ALL.into_iter().filter(|x| *x != Locale::English)
+        {
+            let check = |name: &str, path: &str| {
+                let translated = locale.translate(&format!("name.{name}"));
+                assert!(
+                    translated.is_some_and(valid_name),
+                    "'{name}' isn't translated into {locale:?}, or isn't a valid name"
+                );
+                let description = locale.translate(path);
+                assert!(
+                    description.is_some_and(|x| x.chars().count() <= MAX_DESCRIPTION),
+                    "'{path}' isn't translated into {locale:?}, or is too long"
+                );
+            };
+            for command in [COMMAND, TYPED_COMMAND]
+            {
+                check(command.name, command.name);
+                for spec in command.subcommands
+                {
+                    let path = format!("{}.{}", command.name, spec.name);
+                    check(spec.name, &path);
+                    for option in spec.options
+                    {
+                        check(option.name, &format!("{path}.{}", option.name));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_suggest()
+    {
+        let target = TYPED_COMMAND
+            .subcommand("energy")
+            .and_then(|spec| spec.option("target"))
+            .unwrap();
+        assert_eq!(
+            target.suggest("kilo"),
+            [
+                ("Kilojoule(s) [kJ]".to_string(), "kJ".to_string()),
+                ("Kilocalorie(s) [kcal]".to_string(), "kcal".to_string()),
+                ("Kilowatt-hour(s) [kWh]".to_string(), "kWh".to_string()),
+            ]
+        );
+        assert_eq!(target.suggest("").len(), 7);
+    }
+}
@@ -1,111 +1,538 @@
-use std::{fmt, str::FromStr};
+use crate::{
+    i18n::{aliases, Aliases, Locale},
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+};
+use serde_derive::{Deserialize, Serialize};
+use std::{
+    fmt,
+    ops::{Add, Neg, Sub},
+    str::FromStr,
+};
 use thiserror::Error;
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// An absolute temperature, stored in kelvin. Temperatures compare equal
+/// if they're the same temperature, whatever unit they're shown in.
+#[derive(Debug, Clone, Copy)]
 pub struct Temperature
 {
     temp: f64,
     kind: TemperatureUnit,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-enum TemperatureUnit
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub enum TemperatureUnit
 {
     Kelvin,
     Celsius,
     Fahrenheit,
+    Rankine,
+    Reaumur,
+    Delisle,
+    Newton,
+    Romer,
+
+    /// UK oven gas marks. Not a linear scale, so it's converted with a table.
+    GasMark,
 }
 
-#[derive(Error, Debug)]
-pub enum ParseTempError
+/// The `temperature` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "temperature",
+    "Convert between temperature units. Supports K, °C, °F, °R, °Ré, °De, °N, °Rø, and gas marks.",
+)
+.details("Convert from one temperature unit to another. Supports Kelvin, Celsius, Fahrenheit, Rankine, Réaumur, Delisle, Newton, Rømer, and UK gas marks")
+.options(&[
+    OptionSpec::string("value", "Original value (e.g. '65F' [Fahrenheit], '18.33C' [Celsius], 'gas mark 4').")
+        .details("Original value (e.g. '65F' [Fahrenheit], '18.33C' [Celsius], '500R' [Rankine], 'gas mark 4')"),
+    OptionSpec::string("target", "The unit to target (e.g 'F', 'K', 'gas mark'). Defaults to your preferred scale.")
+        .optional()
+        .details("The unit to target. (e.g 'F' [Fahrenheit], 'K' [kelvin], 'Ré' [Réaumur], 'De' [Delisle], 'N' [Newton], 'Rø' [Rømer], 'gas mark'). Defaults to your preferred scale, or swaps Celsius and Fahrenheit"),
+    OptionSpec::boolean("difference", "Treat the value as a difference (e.g. a 10°C rise) instead of a reading.")
+        .details("Treat the value as a difference (e.g. a 10°C rise is an 18°F rise) instead of a reading. Values like 'Δ10C' or '+10C change' are always differences"),
+]);
+
+/// The `temperature` subcommand of the typed command layout.
+pub const TYPED_SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "temperature",
+    "Convert a number from one temperature unit to another.",
+)
+.options(&[
+    OptionSpec::number("value", "The number to convert (e.g. 65)."),
+    OptionSpec::string("unit", "The unit the number is in.").choices(choices),
+    OptionSpec::string(
+        "target",
+        "The unit to convert to. Defaults to your preferred scale.",
+    )
+    .optional()
+    .choices(choices),
+    OptionSpec::boolean(
+        "difference",
+        "Treat the value as a difference (e.g. a 10°C rise) instead of a reading.",
+    ),
+]);
+
+pub fn run(value: String, target: String, difference: bool) -> String
 {
-    #[error("Invalid unit provided")]
-    InvalidUnit,
+    convert(value, Some(target), difference, Locale::English).to_string()
+}
 
-    #[error("Invalid number provided: {0}")]
-    InvalidNumber(String),
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+/// Without a target, Celsius and Fahrenheit are converted to each other, and
+/// anything else to Celsius.
+pub fn convert(value: String, target: Option<String>, difference: bool, locale: Locale) -> Reply
+{
+    let target = match target.map(|x| TemperatureUnit::from_str(&x)).transpose()
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    if difference || TemperatureDelta::is_marked(&value)
+    {
+        match TemperatureDelta::from_str(&value)
+        {
+            Ok(delta) => convert_delta(delta, target, locale),
+            Err(e) => Reply::Error(e.text(locale)),
+        }
+    }
+    else
+    {
+        match Temperature::from_str(&value)
+        {
+            Ok(temp) => convert_temperature(temp, target, locale),
+            Err(e) => Reply::Error(e.text(locale)),
+        }
+    }
 }
 
-impl FromStr for Temperature
+/// Like [`convert`], but with the number and its unit given separately (e.g.
+/// from typed slash command options).
+pub fn convert_value(
+    value: f64,
+    unit: String,
+    target: Option<String>,
+    difference: bool,
+    locale: Locale,
+) -> Reply
 {
-    type Err = ParseTempError;
+    let (unit, target) = match (
+        TemperatureUnit::from_str(&unit),
+        target.map(|x| TemperatureUnit::from_str(&x)).transpose(),
+    )
+    {
+        (Ok(unit), Ok(target)) => (unit, target),
+        (Err(e), _) | (_, Err(e)) => return Reply::Error(e.text(locale)),
+    };
 
-    fn from_str(s: &str) -> Result<Self, Self::Err>
+    if difference
     {
-        let mut s = s.to_lowercase();
-        let kind;
-        s = match s
+        match TemperatureDelta::new(value, unit)
         {
-            _ if s.ends_with('c') || s.ends_with("celsius") || s.ends_with("cel") =>
+            Ok(delta) => convert_delta(delta, target, locale),
+            Err(e) => Reply::Error(e.text(locale)),
+        }
+    }
+    else
+    {
+        match Temperature::new(value, unit)
+        {
+            Ok(temp) => convert_temperature(temp, target, locale),
+            Err(e) => Reply::Error(e.text(locale)),
+        }
+    }
+}
+
+/// Convert `temp` into `target`, or its unit's counterpart.
+pub(crate) fn convert_temperature(
+    mut temp: Temperature,
+    target: Option<TemperatureUnit>,
+    locale: Locale,
+) -> Reply
+{
+    let target = target.unwrap_or_else(|| temp.unit().counterpart());
+    let original = temp;
+    conversion(
+        original.text(locale),
+        temp.as_unit(target).text(locale),
+        original.unit(),
+        target,
+        locale,
+    )
+}
+
+fn convert_delta(
+    mut delta: TemperatureDelta,
+    target: Option<TemperatureUnit>,
+    locale: Locale,
+) -> Reply
+{
+    let target = target.unwrap_or_else(|| delta.unit().counterpart());
+    if target == TemperatureUnit::GasMark
+    {
+        return Reply::Error(ParseTempError::GasMarkDifference.text(locale));
+    }
+    let original = delta;
+    conversion(
+        original.text(locale),
+        delta.as_unit(target).text(locale),
+        original.unit(),
+        target,
+        locale,
+    )
+}
+
+fn conversion(
+    input: String,
+    output: String,
+    unit: TemperatureUnit,
+    target: TemperatureUnit,
+    locale: Locale,
+) -> Reply
+{
+    Reply::Conversion(Conversion {
+        input,
+        output,
+        units: Some((unit.name(locale), target.name(locale))),
+        ..Default::default()
+    })
+}
+
+/// Each unit as a slash command choice.
+pub fn choices() -> Vec<(String, String)>
+{
+    TemperatureUnit::ALL
+        .iter()
+        .map(|unit| (unit.to_string(), format!("{unit:?}").to_lowercase()))
+        .collect()
+}
+
+/// Unit suffixes, as written after the number (e.g. '65F', '10 newton').
+const SUFFIXES: &[(&str, TemperatureUnit)] = &[
+    ("c", TemperatureUnit::Celsius),
+    ("cel", TemperatureUnit::Celsius),
+    ("celsius", TemperatureUnit::Celsius),
+    ("f", TemperatureUnit::Fahrenheit),
+    ("fah", TemperatureUnit::Fahrenheit),
+    ("fahrenheit", TemperatureUnit::Fahrenheit),
+    ("k", TemperatureUnit::Kelvin),
+    ("kel", TemperatureUnit::Kelvin),
+    ("kelvin", TemperatureUnit::Kelvin),
+    ("r", TemperatureUnit::Rankine),
+    ("ra", TemperatureUnit::Rankine),
+    ("rankine", TemperatureUnit::Rankine),
+    ("re", TemperatureUnit::Reaumur),
+    ("ré", TemperatureUnit::Reaumur),
+    ("reaumur", TemperatureUnit::Reaumur),
+    ("réaumur", TemperatureUnit::Reaumur),
+    ("de", TemperatureUnit::Delisle),
+    ("delisle", TemperatureUnit::Delisle),
+    ("n", TemperatureUnit::Newton),
+    ("newton", TemperatureUnit::Newton),
+    ("ro", TemperatureUnit::Romer),
+    ("rø", TemperatureUnit::Romer),
+    ("romer", TemperatureUnit::Romer),
+    ("rømer", TemperatureUnit::Romer),
+];
+
+/// Unit suffixes in other languages. A bare word for 'degrees' means Celsius,
+/// as it does wherever those languages are spoken.
+const LOCALISED_SUFFIXES: &[Aliases<TemperatureUnit>] = &[
+    (
+        "de",
+        &[
+            ("grad", TemperatureUnit::Celsius),
+            ("grad celsius", TemperatureUnit::Celsius),
+            ("grad fahrenheit", TemperatureUnit::Fahrenheit),
+        ],
+    ),
+    (
+        "es",
+        &[
+            ("grado", TemperatureUnit::Celsius),
+            ("grados", TemperatureUnit::Celsius),
+            ("grados celsius", TemperatureUnit::Celsius),
+            ("grados centígrados", TemperatureUnit::Celsius),
+            ("grados fahrenheit", TemperatureUnit::Fahrenheit),
+        ],
+    ),
+    (
+        "ru",
+        &[
+            // One, a few, and many degrees
+            ("градус", TemperatureUnit::Celsius),
+            ("градуса", TemperatureUnit::Celsius),
+            ("градусов", TemperatureUnit::Celsius),
+            ("градус цельсия", TemperatureUnit::Celsius),
+            ("градуса цельсия", TemperatureUnit::Celsius),
+            ("градусов цельсия", TemperatureUnit::Celsius),
+            ("градус по фаренгейту", TemperatureUnit::Fahrenheit),
+            ("градуса по фаренгейту", TemperatureUnit::Fahrenheit),
+            ("градусов по фаренгейту", TemperatureUnit::Fahrenheit),
+        ],
+    ),
+];
+
+/// Every unit suffix, in English and other languages.
+fn suffixes() -> impl Iterator<Item = (&'static str, TemperatureUnit)>
+{
+    SUFFIXES.iter().copied().chain(aliases(LOCALISED_SUFFIXES))
+}
+
+/// Units that are read as temperatures in running text without a degree sign.
+/// The rest are too easily mistaken for other things (e.g. '5K' for 5000).
+const UNMARKED_SUFFIXES: &[&str] = &["c", "f", "celsius", "fahrenheit", "kelvin"];
+
+/// Gas mark prefixes, as written before the number (e.g. 'gas mark 4').
+const GAS_MARK_PREFIXES: &[&str] = &["gas mark", "gasmark", "gas", "mark", "gm"];
+
+/// Gas marks and their oven temperatures in Fahrenheit.
+const GAS_MARKS: &[(f64, f64)] = &[
+    (0.25, 225.0),
+    (0.5, 250.0),
+    (1.0, 275.0),
+    (2.0, 300.0),
+    (3.0, 325.0),
+    (4.0, 350.0),
+    (5.0, 375.0),
+    (6.0, 400.0),
+    (7.0, 425.0),
+    (8.0, 450.0),
+    (9.0, 475.0),
+    (10.0, 500.0),
+];
+
+impl TemperatureUnit
+{
+    pub const ALL: [Self; 9] = [
+        Self::Kelvin,
+        Self::Celsius,
+        Self::Fahrenheit,
+        Self::Rankine,
+        Self::Reaumur,
+        Self::Delisle,
+        Self::Newton,
+        Self::Romer,
+        Self::GasMark,
+    ];
+
+    /// The unit's name in `locale` (e.g. 'Celsius', 'Gas mark').
+    pub fn name(self, locale: Locale) -> String
+    {
+        locale.text(&format!("unit.{}", format!("{self:?}").to_lowercase()), &[])
+    }
+
+    /// `value` written with the unit in `locale` (e.g. '20 Celsius', 'Gas mark
+    /// 4').
+    fn describe(self, value: &str, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::GasMark => locale.text("temperature.gas-mark", &[("mark", value)]),
+            unit => format!("{value} {}", unit.name(locale)),
+        }
+    }
+
+    /// The other of Celsius and Fahrenheit, which is what's converted to when
+    /// no target is given.
+    pub fn counterpart(self) -> Self
+    {
+        match self
+        {
+            Self::Celsius => Self::Fahrenheit,
+            _ => Self::Celsius,
+        }
+    }
+
+    /// The size of one degree in kelvin. Delisle counts downwards, so its
+    /// degree is negative. Gas marks have no fixed degree, so they can't be
+    /// used for differences.
+    fn degree(&self) -> f64
+    {
+        match self
+        {
+            Self::Kelvin | Self::Celsius | Self::GasMark => 1.0,
+            Self::Fahrenheit | Self::Rankine => 5.0 / 9.0,
+            Self::Reaumur => 5.0 / 4.0,
+            Self::Delisle => -2.0 / 3.0,
+            Self::Newton => 100.0 / 33.0,
+            Self::Romer => 40.0 / 21.0,
+        }
+    }
+
+    fn to_kelvin(self, x: f64) -> f64
+    {
+        match self
+        {
+            Self::Kelvin => x,
+            Self::Celsius => x + 273.15,
+            Self::Fahrenheit => (x - 32.0) * 5.0 / 9.0 + 273.15,
+            Self::Rankine => x * 5.0 / 9.0,
+            Self::Reaumur => x * 5.0 / 4.0 + 273.15,
+            Self::Delisle => 373.15 - x * 2.0 / 3.0,
+            Self::Newton => x * 100.0 / 33.0 + 273.15,
+            Self::Romer => (x - 7.5) * 40.0 / 21.0 + 273.15,
+            Self::GasMark =>
             {
-                kind = TemperatureUnit::Celsius;
-                match s.strip_suffix('c')
-                {
-                    Some(x) => x.to_string(),
-                    None => match s.strip_suffix("celsius")
-                    {
-                        Some(x) => x.to_string(),
-                        None => match s.strip_suffix("cel")
-                        {
-                            Some(x) => x.to_string(),
-                            None => s,
-                        },
-                    },
-                }
+                // Interpolate between the marks in the table
+                let fahrenheit = GAS_MARKS
+                    .windows(2)
+                    .find(|w| x <= w[1].0)
+                    .map(|w| w[0].1 + (x - w[0].0) / (w[1].0 - w[0].0) * (w[1].1 - w[0].1))
+                    .unwrap_or(GAS_MARKS[GAS_MARKS.len() - 1].1);
+                Self::Fahrenheit.to_kelvin(fahrenheit)
             }
+        }
+    }
 
-            _ if s.ends_with('f') || s.ends_with("fahrenheit") || s.ends_with("fah") =>
+    fn kelvin_to(self, k: f64) -> f64
+    {
+        match self
+        {
+            Self::Kelvin => k,
+            Self::Celsius => k - 273.15,
+            Self::Fahrenheit => (k - 273.15) * 9.0 / 5.0 + 32.0,
+            Self::Rankine => k * 9.0 / 5.0,
+            Self::Reaumur => (k - 273.15) * 4.0 / 5.0,
+            Self::Delisle => (373.15 - k) * 3.0 / 2.0,
+            Self::Newton => (k - 273.15) * 33.0 / 100.0,
+            Self::Romer => (k - 273.15) * 21.0 / 40.0 + 7.5,
+            // The nearest mark
+            Self::GasMark =>
             {
-                kind = TemperatureUnit::Fahrenheit;
-                match s.strip_suffix('f')
-                {
-                    Some(x) => x.to_string(),
-                    None => match s.strip_suffix("fahrenheit")
-                    {
-                        Some(x) => x.to_string(),
-                        None => match s.strip_suffix("fah")
-                        {
-                            Some(x) => x.to_string(),
-                            None => s,
-                        },
-                    },
-                }
+                let fahrenheit = Self::Fahrenheit.kelvin_to(k);
+                GAS_MARKS
+                    .iter()
+                    .min_by(|a, b| {
+                        (a.1 - fahrenheit)
+                            .abs()
+                            .total_cmp(&(b.1 - fahrenheit).abs())
+                    })
+                    .map(|x| x.0)
+                    .unwrap_or_default()
             }
+        }
+    }
+}
 
-            _ if s.ends_with('k') || s.ends_with("kelvin") =>
+impl FromStr for TemperatureUnit
+{
+    type Err = ParseTempError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim().trim_start_matches('°').to_lowercase();
+        if GAS_MARK_PREFIXES.contains(&&*s)
+        {
+            return Ok(Self::GasMark);
+        }
+        suffixes()
+            .find(|(suffix, _)| *suffix == s)
+            .map(|(_, unit)| unit)
+            .ok_or(ParseTempError::InvalidUnit)
+    }
+}
+
+impl fmt::Display for TemperatureUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum ParseTempError
+{
+    InvalidUnit,
+    InvalidNumber(String),
+    GasMarkDifference,
+
+    /// A value and the unit it was given in
+    BelowAbsoluteZero(f64, TemperatureUnit),
+    NotFinite(f64, TemperatureUnit),
+    OutOfRange(f64, TemperatureUnit),
+}
+
+impl ParseTempError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        let reading = |key, value: &f64, unit: &TemperatureUnit| {
+            locale.text(
+                key,
+                &[("value", &unit.describe(&value.to_string(), locale))],
+            )
+        };
+        match self
+        {
+            Self::InvalidUnit => locale.text("error.temperature.invalid-unit", &[]),
+            Self::InvalidNumber(number) =>
+            {
+                locale.text("error.invalid-number", &[("number", number)])
+            }
+            Self::GasMarkDifference => locale.text("error.temperature.gas-mark-difference", &[]),
+            Self::BelowAbsoluteZero(value, unit) =>
             {
-                kind = TemperatureUnit::Kelvin;
-                match s.strip_suffix('k')
-                {
-                    Some(x) => x.to_string(),
-                    None => match s.strip_suffix("kelvin")
-                    {
-                        Some(x) => x.to_string(),
-                        None => match s.strip_suffix("kel")
-                        {
-                            Some(x) => x.to_string(),
-                            None => s,
-                        },
-                    },
-                }
+                reading("error.temperature.below-absolute-zero", value, unit)
             }
+            Self::NotFinite(value, unit) => reading("error.temperature.not-finite", value, unit),
+            Self::OutOfRange(value, unit) => reading("error.temperature.out-of-range", value, unit),
+        }
+    }
+}
 
-            _ => return Err(Self::Err::InvalidUnit),
-        };
+/// Parse a gas mark number, including fractions (e.g. '1/4', '½').
+fn parse_gas_mark(s: &str) -> Result<f64, ParseTempError>
+{
+    match s.trim()
+    {
+        "¼" | "1/4" => Ok(0.25),
+        "½" | "1/2" => Ok(0.5),
+        s => s
+            .parse()
+            .map_err(|_| ParseTempError::InvalidNumber(s.to_string())),
+    }
+}
 
-        Ok(Self {
-            kind,
-            temp: match s.trim().parse()
-            {
-                Ok(x) => match kind
-                {
-                    TemperatureUnit::Kelvin => x,
-                    TemperatureUnit::Celsius => x + 273.15,
-                    TemperatureUnit::Fahrenheit => (x - 32.0) * 5.0 / 9.0 + 273.15,
-                },
-                Err(_) => return Err(Self::Err::InvalidNumber(s.trim().to_string())),
-            },
-        })
+/// Split a value like '65F' into its number and unit, without converting it.
+fn parse_value(s: &str) -> Result<(f64, TemperatureUnit), ParseTempError>
+{
+    let s = s.trim().to_lowercase();
+    if let Some(mark) = GAS_MARK_PREFIXES
+        .iter()
+        .find_map(|prefix| s.strip_prefix(prefix))
+    {
+        return Ok((parse_gas_mark(mark)?, TemperatureUnit::GasMark));
+    }
+
+    // The longest matching suffix, so 'kelvin' isn't read as Newton
+    let (suffix, kind) = suffixes()
+        .filter(|(suffix, _)| s.ends_with(suffix))
+        .max_by_key(|(suffix, _)| suffix.len())
+        .ok_or(ParseTempError::InvalidUnit)?;
+    let s = s[..s.len() - suffix.len()]
+        .trim()
+        .trim_end_matches('°')
+        .trim();
+
+    match s.parse()
+    {
+        Ok(x) => Ok((x, kind)),
+        Err(_) => Err(ParseTempError::InvalidNumber(s.to_string())),
+    }
+}
+
+impl FromStr for Temperature
+{
+    type Err = ParseTempError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (x, kind) = parse_value(s)?;
+        Self::new(x, kind)
     }
 }
 
@@ -113,19 +540,228 @@ impl std::fmt::Display for Temperature
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
-        let (temp, unit) = match self.kind
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+impl Temperature
+{
+    /// The Planck temperature in kelvin, the highest temperature that makes
+    /// physical sense.
+    pub const MAX_KELVIN: f64 = 1.416784e32;
+
+    /// Create a temperature of `value` in `unit`, rejecting values which
+    /// aren't physically possible.
+    pub fn new(value: f64, unit: TemperatureUnit) -> Result<Self, ParseTempError>
+    {
+        if !value.is_finite()
         {
-            TemperatureUnit::Kelvin => (self.temp, "Kelvin"),
-            TemperatureUnit::Celsius => (self.temp - 273.15, "Celsius"),
-            TemperatureUnit::Fahrenheit => ((self.temp - 273.15) * 9.0 / 5.0 + 32.0, "Fahrenheit"),
-        };
+            return Err(ParseTempError::NotFinite(value, unit));
+        }
+        if unit == TemperatureUnit::GasMark
+            && !(GAS_MARKS[0].0..=GAS_MARKS[GAS_MARKS.len() - 1].0).contains(&value)
+        {
+            return Err(ParseTempError::OutOfRange(value, unit));
+        }
+
+        let temp = unit.to_kelvin(value);
+        // Allow for rounding errors when converting exactly absolute zero
+        if temp < -1e-9
+        {
+            return Err(ParseTempError::BelowAbsoluteZero(value, unit));
+        }
+        if temp > Self::MAX_KELVIN
+        {
+            return Err(ParseTempError::OutOfRange(value, unit));
+        }
 
-        write!(f, "{temp:.2} {unit}")
+        Ok(Self {
+            temp: temp.max(0.0),
+            kind: unit,
+        })
+    }
+
+    /// Parse a temperature written in running text (e.g. '30°C', '86 F'). This
+    /// is stricter than [`Temperature::from_str`], so a number followed by a
+    /// word isn't taken as a temperature.
+    pub fn scan(s: &str) -> Option<Self>
+    {
+        let s = s.trim();
+        let unit = s
+            .trim_start_matches(|c: char| c.is_ascii_digit() || matches!(c, '-' | '+' | '.'))
+            .trim();
+        if unit.len() == s.len()
+        {
+            return None;
+        }
+        let marked = unit.starts_with('°');
+        if !marked && !UNMARKED_SUFFIXES.contains(&&*unit.to_lowercase())
+        {
+            return None;
+        }
+        Self::from_str(s).ok()
+    }
+
+    /// The temperature as text in `locale` (e.g. '35.00 Celsius').
+    pub fn text(&self, locale: Locale) -> String
+    {
+        if self.kind == TemperatureUnit::GasMark
+        {
+            let fahrenheit = TemperatureUnit::Fahrenheit.kelvin_to(self.temp);
+            let (lowest, highest) = (GAS_MARKS[0].1, GAS_MARKS[GAS_MARKS.len() - 1].1);
+            return match self.kind.kelvin_to(self.temp)
+            {
+                _ if fahrenheit < lowest - 12.5 => locale.text("temperature.below-gas-marks", &[]),
+                _ if fahrenheit > highest + 12.5 => locale.text("temperature.above-gas-marks", &[]),
+                0.25 => self.kind.describe("1/4", locale),
+                0.5 => self.kind.describe("1/2", locale),
+                x => self.kind.describe(&x.to_string(), locale),
+            };
+        }
+
+        self.kind.describe(&format!("{:.2}", self.value()), locale)
+    }
+
+    /// The unit the temperature is shown in.
+    pub fn unit(&self) -> TemperatureUnit { self.kind }
+
+    /// The temperature in its own unit.
+    pub fn value(&self) -> f64 { self.value_in(self.kind) }
+
+    /// The temperature in `unit`. Gas marks are rounded to the nearest mark.
+    pub fn value_in(&self, unit: TemperatureUnit) -> f64 { unit.kelvin_to(self.temp) }
+
+    /// Show the temperature in `unit`.
+    pub fn as_unit(&mut self, unit: TemperatureUnit) -> &mut Self
+    {
+        self.kind = unit;
+        self
+    }
+
+    /// Add a difference, failing instead of going below absolute zero, or off
+    /// the gas mark table for gas marks.
+    pub fn checked_add(self, delta: TemperatureDelta) -> Result<Self, ParseTempError>
+    {
+        let mut temp = Self::new(self.temp + delta.delta, TemperatureUnit::Kelvin)?;
+        if self.kind == TemperatureUnit::GasMark
+        {
+            let lowest = TemperatureUnit::GasMark.to_kelvin(GAS_MARKS[0].0);
+            let highest = TemperatureUnit::GasMark.to_kelvin(GAS_MARKS[GAS_MARKS.len() - 1].0);
+            if !(lowest - 1e-9..=highest + 1e-9).contains(&temp.temp)
+            {
+                let fahrenheit = temp.value_in(TemperatureUnit::Fahrenheit);
+                return Err(ParseTempError::OutOfRange(
+                    fahrenheit,
+                    TemperatureUnit::Fahrenheit,
+                ));
+            }
+        }
+        temp.kind = self.kind;
+        Ok(temp)
+    }
+
+    /// Subtract a difference, failing like [`Temperature::checked_add`].
+    pub fn checked_sub(self, delta: TemperatureDelta) -> Result<Self, ParseTempError>
+    {
+        self.checked_add(-delta)
+    }
+
+    pub fn as_cel(&mut self) -> &mut Self
+    {
+        self.kind = TemperatureUnit::Celsius;
+        self
+    }
+
+    pub fn as_kel(&mut self) -> &mut Self
+    {
+        self.kind = TemperatureUnit::Kelvin;
+        self
+    }
+
+    pub fn as_fah(&mut self) -> &mut Self
+    {
+        self.kind = TemperatureUnit::Fahrenheit;
+        self
     }
 }
 
-impl Temperature
+#[derive(Debug, Clone, Copy)]
+/// A difference between two temperatures (e.g. 'a 10°C rise'), stored in
+/// kelvin. Unlike `Temperature`, converting it only scales the value, there's
+/// no offset.
+pub struct TemperatureDelta
+{
+    delta: f64,
+    kind: TemperatureUnit,
+}
+
+impl TemperatureDelta
 {
+    const PREFIXES: [&'static str; 2] = ["δ", "delta"];
+    const SUFFIXES: [&'static str; 4] = ["change", "difference", "rise", "increase"];
+    const NEGATIVE_SUFFIXES: [&'static str; 3] = ["drop", "decrease", "fall"];
+
+    /// Whether the input is explicitly written as a difference (e.g. 'Δ10C',
+    /// '+10C change').
+    pub fn is_marked(s: &str) -> bool
+    {
+        let s = s.trim().to_lowercase();
+        Self::PREFIXES.iter().any(|x| s.starts_with(x))
+            || Self::SUFFIXES
+                .iter()
+                .chain(Self::NEGATIVE_SUFFIXES.iter())
+                .any(|x| s.ends_with(x))
+    }
+
+    /// Create a difference of `value` degrees of `unit`.
+    pub fn new(value: f64, unit: TemperatureUnit) -> Result<Self, ParseTempError>
+    {
+        if unit == TemperatureUnit::GasMark
+        {
+            return Err(ParseTempError::GasMarkDifference);
+        }
+        if !value.is_finite()
+        {
+            return Err(ParseTempError::NotFinite(value, unit));
+        }
+        if (value * unit.degree()).abs() > Temperature::MAX_KELVIN
+        {
+            return Err(ParseTempError::OutOfRange(value, unit));
+        }
+
+        Ok(Self {
+            delta: value * unit.degree(),
+            kind: unit,
+        })
+    }
+
+    /// The difference as text in `locale` (e.g. 'Δ10.00 Celsius').
+    pub fn text(&self, locale: Locale) -> String
+    {
+        format!("Δ{:.2} {}", self.value(), self.kind.name(locale))
+    }
+
+    /// The unit the difference is shown in.
+    pub fn unit(&self) -> TemperatureUnit { self.kind }
+
+    /// The difference in degrees of its own unit.
+    pub fn value(&self) -> f64 { self.value_in(self.kind) }
+
+    /// The difference in degrees of `unit`. Gas marks have no degrees, so
+    /// this is the difference in kelvin for them.
+    pub fn value_in(&self, unit: TemperatureUnit) -> f64 { self.delta / unit.degree() }
+
+    /// Show the difference in `unit`. Gas marks are shown in Celsius.
+    pub fn as_unit(&mut self, unit: TemperatureUnit) -> &mut Self
+    {
+        self.kind = match unit
+        {
+            TemperatureUnit::GasMark => TemperatureUnit::Celsius,
+            unit => unit,
+        };
+        self
+    }
+
     pub fn as_cel(&mut self) -> &mut Self
     {
         self.kind = TemperatureUnit::Celsius;
@@ -144,3 +780,395 @@ impl Temperature
         self
     }
 }
+
+impl FromStr for TemperatureDelta
+{
+    type Err = ParseTempError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let mut s = s.trim().to_lowercase();
+        for prefix in Self::PREFIXES
+        {
+            if let Some(x) = s.strip_prefix(prefix)
+            {
+                s = x.to_string();
+            }
+        }
+
+        let mut sign = 1.0;
+        for suffix in Self::SUFFIXES
+        {
+            if let Some(x) = s.strip_suffix(suffix)
+            {
+                s = x.trim().to_string();
+            }
+        }
+        for suffix in Self::NEGATIVE_SUFFIXES
+        {
+            if let Some(x) = s.strip_suffix(suffix)
+            {
+                s = x.trim().to_string();
+                sign = -1.0;
+            }
+        }
+
+        let (x, kind) = parse_value(&s)?;
+        Self::new(sign * x, kind)
+    }
+}
+
+impl fmt::Display for TemperatureDelta
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+impl PartialEq for Temperature
+{
+    fn eq(&self, other: &Self) -> bool { self.temp == other.temp }
+}
+
+impl PartialOrd for Temperature
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
+    {
+        self.temp.partial_cmp(&other.temp)
+    }
+}
+
+impl PartialEq for TemperatureDelta
+{
+    fn eq(&self, other: &Self) -> bool { self.delta == other.delta }
+}
+
+impl PartialOrd for TemperatureDelta
+{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>
+    {
+        self.delta.partial_cmp(&other.delta)
+    }
+}
+
+/// Adding and subtracting differences can fail, like
+/// [`Temperature::checked_add`].
+impl Add<TemperatureDelta> for Temperature
+{
+    type Output = Result<Temperature, ParseTempError>;
+
+    fn add(self, rhs: TemperatureDelta) -> Self::Output { self.checked_add(rhs) }
+}
+
+impl Sub<TemperatureDelta> for Temperature
+{
+    type Output = Result<Temperature, ParseTempError>;
+
+    fn sub(self, rhs: TemperatureDelta) -> Self::Output { self.checked_sub(rhs) }
+}
+
+impl Sub for Temperature
+{
+    type Output = TemperatureDelta;
+
+    fn sub(self, rhs: Self) -> Self::Output
+    {
+        TemperatureDelta {
+            delta: self.temp - rhs.temp,
+            kind: match self.kind
+            {
+                TemperatureUnit::GasMark => TemperatureUnit::Celsius,
+                kind => kind,
+            },
+        }
+    }
+}
+
+impl Add for TemperatureDelta
+{
+    type Output = TemperatureDelta;
+
+    fn add(self, rhs: Self) -> Self::Output
+    {
+        Self {
+            delta: self.delta + rhs.delta,
+            ..self
+        }
+    }
+}
+
+impl Sub for TemperatureDelta
+{
+    type Output = TemperatureDelta;
+
+    fn sub(self, rhs: Self) -> Self::Output { self + -rhs }
+}
+
+impl Neg for TemperatureDelta
+{
+    type Output = TemperatureDelta;
+
+    fn neg(self) -> Self::Output
+    {
+        Self {
+            delta: -self.delta,
+            ..self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_delta_conversion()
+    {
+        assert_eq!(
+            run("Δ10C".to_string(), "F".to_string(), false),
+            "Δ10.00 Celsius -> Δ18.00 Fahrenheit"
+        );
+        assert_eq!(
+            run("+10C change".to_string(), "K".to_string(), false),
+            "Δ10.00 Celsius -> Δ10.00 Kelvin"
+        );
+        assert_eq!(
+            run("10C".to_string(), "F".to_string(), true),
+            "Δ10.00 Celsius -> Δ18.00 Fahrenheit"
+        );
+        assert_eq!(
+            run("5F drop".to_string(), "F".to_string(), false),
+            "Δ-5.00 Fahrenheit -> Δ-5.00 Fahrenheit"
+        );
+    }
+
+    #[test]
+    fn test_absolute_conversion()
+    {
+        assert_eq!(
+            run("10C".to_string(), "F".to_string(), false),
+            "10.00 Celsius -> 50.00 Fahrenheit"
+        );
+    }
+
+    #[test]
+    fn test_localised_units()
+    {
+        for (input, unit) in [
+            ("30 grados", TemperatureUnit::Celsius),
+            ("30 Grad", TemperatureUnit::Celsius),
+            ("30 градусов", TemperatureUnit::Celsius),
+            ("2 градуса цельсия", TemperatureUnit::Celsius),
+            ("86 grados fahrenheit", TemperatureUnit::Fahrenheit),
+        ]
+        {
+            assert_eq!(
+                Temperature::from_str(input).unwrap().unit(),
+                unit,
+                "{input}"
+            );
+        }
+        assert_eq!(
+            run("30 Grad".to_string(), "F".to_string(), false),
+            "30.00 Celsius -> 86.00 Fahrenheit"
+        );
+        assert_eq!(
+            TemperatureUnit::from_str("градусов").unwrap(),
+            TemperatureUnit::Celsius
+        );
+    }
+
+    #[test]
+    fn test_historical_scales()
+    {
+        let temp = Temperature::from_str("100C").unwrap();
+        let convert = |unit| temp.clone().as_unit(unit).to_string();
+        assert_eq!(convert(TemperatureUnit::Rankine), "671.67 Rankine");
+        assert_eq!(convert(TemperatureUnit::Reaumur), "80.00 Réaumur");
+        assert_eq!(convert(TemperatureUnit::Delisle), "0.00 Delisle");
+        assert_eq!(convert(TemperatureUnit::Newton), "33.00 Newton");
+        assert_eq!(convert(TemperatureUnit::Romer), "60.00 Rømer");
+
+        assert_eq!(
+            run("150 De".to_string(), "C".to_string(), false),
+            "150.00 Delisle -> 0.00 Celsius"
+        );
+        assert_eq!(
+            run("60rø".to_string(), "kelvin".to_string(), false),
+            "60.00 Rømer -> 373.15 Kelvin"
+        );
+    }
+
+    #[test]
+    fn test_gas_marks()
+    {
+        assert_eq!(
+            run("gas mark 4".to_string(), "C".to_string(), false),
+            "Gas mark 4 -> 176.67 Celsius"
+        );
+        assert_eq!(
+            run("200C".to_string(), "gas mark".to_string(), false),
+            "200.00 Celsius -> Gas mark 6"
+        );
+        assert_eq!(
+            run("gas mark 1/2".to_string(), "F".to_string(), false),
+            "Gas mark 1/2 -> 250.00 Fahrenheit"
+        );
+        assert_eq!(
+            run("20C".to_string(), "gas mark".to_string(), false),
+            "20.00 Celsius -> Below gas mark 1/4"
+        );
+        assert!(Temperature::from_str("gas mark 11").is_err());
+        assert!(TemperatureDelta::from_str("Δ gas mark 2").is_err());
+    }
+
+    #[test]
+    fn test_validation()
+    {
+        assert!(matches!(
+            Temperature::from_str("-500C"),
+            Err(ParseTempError::BelowAbsoluteZero(..))
+        ));
+        assert!(matches!(
+            Temperature::from_str("NaNK"),
+            Err(ParseTempError::NotFinite(..))
+        ));
+        assert!(matches!(
+            Temperature::from_str("infF"),
+            Err(ParseTempError::NotFinite(..))
+        ));
+        assert!(matches!(
+            Temperature::from_str("1e40K"),
+            Err(ParseTempError::OutOfRange(..))
+        ));
+        assert!(matches!(
+            Temperature::from_str("gas mark 11"),
+            Err(ParseTempError::OutOfRange(..))
+        ));
+        assert!(matches!(
+            TemperatureDelta::from_str("Δinf C"),
+            Err(ParseTempError::NotFinite(..))
+        ));
+
+        // Exactly absolute zero is fine in any unit
+        assert_eq!(
+            Temperature::from_str("-459.67F").unwrap().to_string(),
+            "-459.67 Fahrenheit"
+        );
+        assert_eq!(
+            Temperature::new(-273.15, TemperatureUnit::Celsius)
+                .unwrap()
+                .to_string(),
+            "-273.15 Celsius"
+        );
+        assert!(Temperature::new(-1.0, TemperatureUnit::Kelvin).is_err());
+    }
+
+    #[test]
+    fn test_numeric_api()
+    {
+        let temp = Temperature::new(25.0, TemperatureUnit::Celsius).unwrap();
+        assert_eq!(temp.unit(), TemperatureUnit::Celsius);
+        assert_eq!(temp.value(), 25.0);
+        assert_eq!(temp.value_in(TemperatureUnit::Kelvin), 298.15);
+        assert!((temp.value_in(TemperatureUnit::Fahrenheit) - 77.0).abs() < 1e-9);
+
+        // Comparisons work across units
+        let freezing = Temperature::new(273.15, TemperatureUnit::Kelvin).unwrap();
+        assert_eq!(
+            freezing,
+            Temperature::new(0.0, TemperatureUnit::Celsius).unwrap()
+        );
+        assert!(temp > freezing);
+        assert!(Temperature::new(70.0, TemperatureUnit::Fahrenheit).unwrap() < temp);
+
+        let rise = TemperatureDelta::new(9.0, TemperatureUnit::Fahrenheit).unwrap();
+        assert_eq!(rise.value_in(TemperatureUnit::Celsius), 5.0);
+        assert_eq!((temp + rise).unwrap().value(), 30.0);
+        assert!(freezing
+            .checked_sub(TemperatureDelta::new(300.0, TemperatureUnit::Kelvin).unwrap())
+            .is_err());
+    }
+
+    #[test]
+    fn test_delta_arithmetic()
+    {
+        let temp = Temperature::from_str("20C").unwrap();
+        let rise = TemperatureDelta::from_str("Δ18F").unwrap();
+        assert_eq!((temp + rise).unwrap().to_string(), "30.00 Celsius");
+        assert_eq!((temp - rise).unwrap().to_string(), "10.00 Celsius");
+
+        // The result still has to be a valid temperature
+        let cold = Temperature::new(1.0, TemperatureUnit::Kelvin).unwrap();
+        let drop = TemperatureDelta::new(5.0, TemperatureUnit::Kelvin).unwrap();
+        assert!(matches!(
+            cold - drop,
+            Err(ParseTempError::BelowAbsoluteZero(..))
+        ));
+        let oven = Temperature::new(9.0, TemperatureUnit::GasMark).unwrap();
+        let rise = TemperatureDelta::new(100.0, TemperatureUnit::Fahrenheit).unwrap();
+        assert!(matches!(oven + rise, Err(ParseTempError::OutOfRange(..))));
+        assert_eq!((oven - rise).unwrap().value(), 5.0);
+
+        let difference = Temperature::from_str("86F").unwrap() - temp;
+        assert_eq!(difference.to_string(), "Δ18.00 Fahrenheit");
+        assert_eq!(
+            difference
+                .clone()
+                .as_unit(TemperatureUnit::Celsius)
+                .to_string(),
+            "Δ10.00 Celsius"
+        );
+    }
+
+    #[test]
+    fn test_typed_options()
+    {
+        for (name, value) in choices()
+        {
+            assert_eq!(TemperatureUnit::from_str(&value).unwrap().to_string(), name);
+        }
+
+        assert_eq!(
+            convert_value(
+                65.0,
+                "fahrenheit".to_string(),
+                Some("celsius".to_string()),
+                false,
+                Locale::English
+            )
+            .to_string(),
+            run("65F".to_string(), "C".to_string(), false)
+        );
+        assert_eq!(
+            convert_value(
+                10.0,
+                "celsius".to_string(),
+                Some("fahrenheit".to_string()),
+                true,
+                Locale::English
+            )
+            .to_string(),
+            "Δ10.00 Celsius -> Δ18.00 Fahrenheit"
+        );
+        assert_eq!(
+            convert("65F".to_string(), None, false, Locale::English).to_string(),
+            "65.00 Fahrenheit -> 18.33 Celsius"
+        );
+        assert_eq!(
+            convert("65F".to_string(), None, false, Locale::German).text(Locale::German),
+            "65.00 Grad Fahrenheit -> 18.33 Grad Celsius"
+        );
+        assert!(convert_value(
+            1.0,
+            "kelvin".to_string(),
+            Some("gasmark".to_string()),
+            true,
+            Locale::English
+        )
+        .to_string()
+        .starts_with("Error"));
+    }
+}
@@ -1,27 +1,192 @@
-use super::strip_suffixes;
-use std::{fmt, str::FromStr};
+use crate::{
+    i18n::Locale,
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+};
+use chrono::{Duration, NaiveTime, Timelike};
+use serde_derive::{Deserialize, Serialize};
+use std::{
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
 use thiserror::Error;
 
-pub fn run(t: String) -> String
+/// The `hours` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new("hours", "Some people don't know how to subtract by '12'.")
+.options(&[
+    OptionSpec::string("time", "Time in 24h time ('6:00', '14:30'), or in 12h time ('4:44am', '6:00pm')")
+        .details("Time in 24h time ('6:00', '14:30', '1430', '14h30', '14:30:05.250'), in 12h time ('4:44am', '2.30 p.m.', 'noon', 'quarter past three'), or in another notation ('5:00:00 decimal', '@500', '7.75h')"),
+    OptionSpec::boolean("lenient", "Normalise out of range times (e.g. '24:00' is midnight the next day) instead of rejecting them.")
+        .details("Normalise out of range times instead of rejecting them (e.g. '24:00' is midnight the next day, '13pm' is 1pm)"),
+    OptionSpec::string("target", "Notation to show the time in: '12h', '24h', 'decimal', 'beats' or 'decimal hours'.")
+        .optional()
+        .details("Notation to show the time in: '12h', '24h', 'decimal' (French revolutionary), 'beats' (Swatch Internet Time, with times read as UTC) or 'decimal hours' ('7.75h'). Swaps 12h and 24h if left out"),
+]);
+
+pub fn run(t: String, lenient: bool, target: Option<String>) -> String
 {
-    if let Ok(mut time) = Time::from_str(&t)
+    convert(t, lenient, target, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+pub fn convert(t: String, lenient: bool, target: Option<String>, locale: Locale) -> Reply
+{
+    let target = match target.map(|x| TimeNotation::from_str(&x)).transpose()
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    let parsed = if lenient
     {
-        let original = time;
-        let opposite = *time.to_opposite();
-        format!("{original} -> {opposite}")
+        Time::from_str_lenient(&t)
     }
     else
     {
-        format!("'{t}' is in improper form. Examples: '12:20 PM' or '17:00:08'.")
+        Time::from_str(&t).map(|time| LenientTime {
+            time,
+            days: 0,
+            normalised: false,
+        })
+    };
+
+    match parsed
+    {
+        Ok(mut lenient) =>
+        {
+            let original = lenient.time;
+            let converted = match target
+            {
+                Some(kind) => *lenient.time.to_notation(kind),
+                None => *lenient.time.to_opposite(),
+            };
+            let mut notes = Vec::new();
+            if lenient.normalised
+            {
+                notes.push(locale.text(
+                    "time.normalised",
+                    &[("time", t.trim()), ("days", &lenient.days_note(locale))],
+                ));
+            }
+            Reply::Conversion(Conversion {
+                input: original.to_string(),
+                output: converted.to_string(),
+                notes,
+                ..Default::default()
+            })
+        }
+        Err(e) => Reply::Error(locale.text(
+            "error.time.improper-form",
+            &[("time", &t), ("error", &e.text(locale))],
+        )),
+    }
+}
+
+/// Format a duration for humans (e.g. '1h 11m 35s'). Durations under a second
+/// are shown in milliseconds.
+pub fn format_duration(duration: Duration) -> String
+{
+    // Unsigned, so the most negative duration doesn't overflow
+    let millis = duration.num_milliseconds().unsigned_abs();
+    let sign = if duration < Duration::zero() { "-" } else { "" };
+    if millis < 1000
+    {
+        return format!("{sign}{millis}ms");
+    }
+
+    let mut seconds = millis / 1000 + u64::from(millis % 1000 >= 500);
+    let mut parts = Vec::new();
+    for (unit, size) in [("d", 86400), ("h", 3600), ("m", 60), ("s", 1)]
+    {
+        let amount = seconds / size;
+        seconds %= size;
+        if amount > 0
+        {
+            parts.push(format!("{amount}{unit}"));
+        }
     }
+
+    format!("{sign}{}", parts.join(" "))
 }
 
-#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Serialize, Deserialize)]
 pub enum TimeNotation
 {
     TwelveHour,
     #[default]
     TwentyFourHour,
+
+    /// French revolutionary time, with 10 hours a day, 100 minutes an hour and
+    /// 100 seconds a minute (e.g. '5:00:00 decimal' for noon)
+    Decimal,
+
+    /// Swatch Internet Time, with 1000 .beats a day counted from midnight in
+    /// Biel Mean Time (UTC+1). Times are taken to be UTC, so noon is
+    /// '@541.66'.
+    Swatch,
+
+    /// Hours as a decimal number, as on timesheets (e.g. '7.75h' for 7:45)
+    DecimalHours,
+}
+
+impl FromStr for TimeNotation
+{
+    type Err = ParseTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let name: String = s
+            .to_lowercase()
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '_' | '.'))
+            .collect();
+        match &*name
+        {
+            "12" | "12h" | "12hour" | "twelvehour" => Ok(Self::TwelveHour),
+            "24" | "24h" | "24hour" | "twentyfourhour" => Ok(Self::TwentyFourHour),
+            "decimal" | "french" | "revolutionary" => Ok(Self::Decimal),
+            "swatch" | "beats" | "internet" | "internettime" => Ok(Self::Swatch),
+            "decimalhours" | "hours" | "timesheet" => Ok(Self::DecimalHours),
+            _ => Err(ParseTimeError::InvalidNotation(s.trim().to_string())),
+        }
+    }
+}
+
+impl fmt::Display for TimeNotation
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let s = match self
+        {
+            Self::TwelveHour => "12h",
+            Self::TwentyFourHour => "24h",
+            Self::Decimal => "decimal",
+            Self::Swatch => "beats",
+            Self::DecimalHours => "decimal hours",
+        };
+
+        write!(f, "{s}")
+    }
+}
+
+impl TimeNotation
+{
+    pub const ALL: [Self; 5] = [
+        Self::TwelveHour,
+        Self::TwentyFourHour,
+        Self::Decimal,
+        Self::Swatch,
+        Self::DecimalHours,
+    ];
+}
+
+/// Each notation as a slash command choice.
+pub fn choices() -> Vec<(String, String)>
+{
+    TimeNotation::ALL
+        .iter()
+        .map(|kind| (kind.to_string(), kind.to_string()))
+        .collect()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Ord, Eq, Default)]
@@ -32,19 +197,89 @@ pub struct Time
     hours: u8,
     minutes: u8,
     seconds: u8,
+    micros: u32,
 }
 
 #[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
 pub enum ParseTimeError
 {
-    #[error("Couldn't parse number: {number}: {message}")]
-    NumberParseError
+    InvalidNumber(String),
+    TooManySections,
+    HourOutOfRange(u32),
+    MinuteOutOfRange(u32),
+    SecondOutOfRange(u32),
+    InvalidTwelveHourHour(u32),
+    Unrecognised(String),
+    NotationOutOfRange(String),
+    InvalidNotation(String),
+}
+
+impl ParseTimeError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
     {
-        number: String, message: String
-    },
+        match self
+        {
+            Self::InvalidNumber(number) =>
+            {
+                locale.text("error.invalid-number", &[("number", number)])
+            }
+            Self::TooManySections => locale.text("error.time.too-many-sections", &[]),
+            Self::HourOutOfRange(hour) => locale.text(
+                "error.time.hour-out-of-range",
+                &[("hour", &hour.to_string())],
+            ),
+            Self::MinuteOutOfRange(minute) => locale.text(
+                "error.time.minute-out-of-range",
+                &[("minute", &minute.to_string())],
+            ),
+            Self::SecondOutOfRange(second) => locale.text(
+                "error.time.second-out-of-range",
+                &[("second", &second.to_string())],
+            ),
+            Self::InvalidTwelveHourHour(hour) => locale.text(
+                "error.time.invalid-twelve-hour-hour",
+                &[("hour", &hour.to_string())],
+            ),
+            Self::Unrecognised(time) => locale.text("error.time.unrecognised", &[("time", time)]),
+            Self::NotationOutOfRange(time) =>
+            {
+                locale.text("error.time.notation-out-of-range", &[("time", time)])
+            }
+            Self::InvalidNotation(notation) =>
+            {
+                locale.text("error.time.invalid-notation", &[("notation", notation)])
+            }
+        }
+    }
+}
 
-    #[error("There were too many sections in the time provided")]
-    TooManySections,
+/// A time parsed by [`Time::from_str_lenient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LenientTime
+{
+    pub time: Time,
+
+    /// How many days the time overflowed into (e.g. 1 for '24:00')
+    pub days: u32,
+
+    /// Whether anything was out of range and had to be normalised
+    pub normalised: bool,
+}
+
+impl LenientTime
+{
+    fn days_note(&self, locale: Locale) -> String
+    {
+        match self.days
+        {
+            0 => String::new(),
+            1 => locale.text("time.next-day", &[]),
+            days => locale.text("time.days-later", &[("days", &days.to_string())]),
+        }
+    }
 }
 
 impl FromStr for Time
@@ -53,88 +288,425 @@ impl FromStr for Time
 
     fn from_str(s: &str) -> Result<Self, Self::Err>
     {
-        let mut s = s.to_lowercase();
-        let mut kind = TimeNotation::TwelveHour;
-        let mut pm = false;
+        Self::parse(s, false).map(|lenient| lenient.time)
+    }
+}
 
-        match s
+impl Time
+{
+    /// Parse a time, normalising anything out of range instead of rejecting
+    /// it. Overflow carries into the next unit (e.g. '23:60' is '00:00' the
+    /// next day). In 12 hour time, hour 0 is taken as 12, and hours after 12
+    /// as 24 hour time (e.g. '13pm' is '13:00').
+    pub fn from_str_lenient(s: &str) -> Result<LenientTime, ParseTimeError> { Self::parse(s, true) }
+
+    /// Parse a time written in running text (e.g. '14:30', '5pm'). Only
+    /// numbers with a colon or 'am'/'pm' are taken as times, so other numbers
+    /// aren't.
+    pub fn scan(s: &str) -> Option<Self>
+    {
+        let lower = s.trim().to_lowercase();
+        if !lower.starts_with(|c: char| c.is_ascii_digit())
+        {
+            return None;
+        }
+        match split_period(&lower)
+        {
+            (s, None) if !s.contains(':') => None,
+            _ => Self::from_str(&lower).ok(),
+        }
+    }
+
+    fn parse(s: &str, lenient: bool) -> Result<LenientTime, ParseTimeError>
+    {
+        let lower = s.trim().to_lowercase();
+        if let Some(time) = parse_notation(&lower)?
+        {
+            return Ok(LenientTime {
+                time,
+                days: 0,
+                normalised: false,
+            });
+        }
+
+        let (s, pm) = split_period(&lower);
+        let kind = match pm
+        {
+            Some(_) => TimeNotation::TwelveHour,
+            None => TimeNotation::TwentyFourHour,
+        };
+
+        // Anything with letters, other than the 'h' in '14h30', is in words
+        if s.chars().any(|c| c.is_alphabetic() && c != 'h')
         {
-            _ if s.ends_with("pm") =>
+            return match parse_words(s, pm)
             {
-                pm = true;
-            }
-            _ if s.ends_with("am") => (),
+                Some(time) => Ok(LenientTime {
+                    time,
+                    days: 0,
+                    normalised: false,
+                }),
+                None => Err(ParseTimeError::Unrecognised(lower.clone())),
+            };
+        }
 
-            _ => kind = TimeNotation::TwentyFourHour,
+        let sections = split_sections(s);
+        // Only allow up to three sections
+        if sections.len() > 3
+        {
+            return Err(ParseTimeError::TooManySections);
         }
-        s = strip_suffixes(s, &["pm", "am"]);
 
-        let sections: Vec<&str> = s.split(':').collect();
-        match sections.len()
+        let mut parts = [0u32; 3];
+        let mut micros = 0;
+        for (i, (part, section)) in parts.iter_mut().zip(sections).enumerate()
         {
-            // Only allow up to three sections
-            1 | 2 | 3 =>
+            // Only seconds can have a fraction (e.g. '12:00:00.250')
+            let section = match section.split_once('.')
             {
-                let mut time = Self::new(kind);
-                for (i, section) in sections.into_iter().enumerate()
+                Some((seconds, fraction)) if i == 2 =>
                 {
-                    match section.trim().parse()
-                    {
-                        Ok(x) => match i
-                        {
-                            0 =>
-                            {
-                                time.hours = {
-                                    if kind == TimeNotation::TwelveHour
-                                    {
-                                        if !pm && x == 12
-                                        {
-                                            x - 12
-                                        }
-                                        else if pm && x < 12
-                                        {
-                                            x + 12
-                                        }
-                                        else
-                                        {
-                                            x
-                                        }
-                                    }
-                                    else
-                                    {
-                                        x
-                                    }
-                                }
-                            }
-                            1 => time.minutes = x,
-                            2 => time.seconds = x,
-                            _ => (),
-                        },
-                        Err(e) =>
-                        {
-                            return Err(Self::Err::NumberParseError {
-                                number: section.to_string(),
-                                message: e.to_string(),
-                            })
-                        }
-                    }
+                    micros = parse_fraction(fraction)?;
+                    seconds
                 }
-                Ok(time)
+                _ => section,
+            };
+
+            *part = section
+                .trim()
+                .parse()
+                .map_err(|_| ParseTimeError::InvalidNumber(section.to_string()))?;
+        }
+        let [mut hours, minutes, seconds] = parts;
+        let mut normalised = false;
+
+        if let Some(pm) = pm
+        {
+            match hours
+            {
+                1..=11 if pm => hours += 12,
+                1..=11 => (),
+                12 if pm => (),
+                12 => hours = 0,
+                _ if !lenient => return Err(ParseTimeError::InvalidTwelveHourHour(hours)),
+                0 =>
+                {
+                    normalised = true;
+                    hours = if pm { 12 } else { 0 };
+                }
+                _ => normalised = true,
             }
-            _ => Err(Self::Err::TooManySections),
+        }
+
+        if !lenient
+        {
+            if hours > 23
+            {
+                return Err(ParseTimeError::HourOutOfRange(hours));
+            }
+            if minutes > 59
+            {
+                return Err(ParseTimeError::MinuteOutOfRange(minutes));
+            }
+            if seconds > 59
+            {
+                return Err(ParseTimeError::SecondOutOfRange(seconds));
+            }
+        }
+
+        let total = u64::from(hours) * 3600 + u64::from(minutes) * 60 + u64::from(seconds);
+        let days = (total / 86400) as u32;
+        let total = total % 86400;
+        Ok(LenientTime {
+            time: Self {
+                kind,
+                hours: (total / 3600) as u8,
+                minutes: (total / 60 % 60) as u8,
+                seconds: (total % 60) as u8,
+                micros,
+            },
+            days,
+            normalised: normalised || hours > 23 || minutes > 59 || seconds > 59,
+        })
+    }
+}
+
+/// Split off an AM or PM suffix (e.g. '2:30pm', '2.30 p.m.'), returning whether
+/// it was PM.
+fn split_period(s: &str) -> (&str, Option<bool>)
+{
+    for (suffix, pm) in [
+        ("p.m.", true),
+        ("p.m", true),
+        ("pm", true),
+        ("a.m.", false),
+        ("a.m", false),
+        ("am", false),
+    ]
+    {
+        if let Some(s) = s.strip_suffix(suffix)
+        {
+            return (s.trim(), Some(pm));
         }
     }
+    (s, None)
+}
+
+/// Split a time into hours, minutes and seconds. Besides '14:30:00', this
+/// accepts military ('1430', '1430h'), French ('14h30') and dotted ('2.30')
+/// times.
+fn split_sections(s: &str) -> Vec<&str>
+{
+    let is_military = |s: &str| (3..=4).contains(&s.len()) && s.bytes().all(|b| b.is_ascii_digit());
+
+    if s.contains(':')
+    {
+        s.split(':').collect()
+    }
+    else if let Some(s) = s.strip_suffix('h').filter(|s| is_military(s))
+    {
+        let (hours, minutes) = s.split_at(s.len() - 2);
+        vec![hours, minutes]
+    }
+    else if let Some((hours, minutes)) = s.split_once('h')
+    {
+        if minutes.is_empty()
+        {
+            vec![hours]
+        }
+        else
+        {
+            vec![hours, minutes]
+        }
+    }
+    else if s.contains('.')
+    {
+        s.split('.').collect()
+    }
+    else if is_military(s)
+    {
+        let (hours, minutes) = s.split_at(s.len() - 2);
+        vec![hours, minutes]
+    }
+    else
+    {
+        vec![s]
+    }
+}
+
+/// Parse the fraction of a second into microseconds, ignoring any digits past
+/// the sixth.
+fn parse_fraction(fraction: &str) -> Result<u32, ParseTimeError>
+{
+    let invalid = || ParseTimeError::InvalidNumber(fraction.to_string());
+    if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+
+    format!("{:0<6}", &fraction[..fraction.len().min(6)])
+        .parse()
+        .map_err(|_| invalid())
+}
+
+const MICROS_PER_DAY: u64 = 86_400_000_000;
+
+/// How far Biel Mean Time, which .beats are counted in, is ahead of UTC.
+const BMT_OFFSET_MICROS: u64 = 3_600_000_000;
+
+/// Parse Swatch Internet Time ('@500', '500 .beats'), decimal time ('5:00:00
+/// decimal') and decimal hours ('7.75h'), returning `None` for anything else.
+fn parse_notation(s: &str) -> Result<Option<Time>, ParseTimeError>
+{
+    let out_of_range = || ParseTimeError::NotationOutOfRange(s.to_string());
+    let number = |x: &str| {
+        x.trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|x| x.is_finite() && *x >= 0.0)
+            .ok_or_else(|| ParseTimeError::InvalidNumber(x.trim().to_string()))
+    };
+
+    let beats = s.strip_prefix('@').unwrap_or(s);
+    let beats = beats
+        .strip_suffix("beats")
+        .map(|x| x.trim_end().trim_end_matches('.'));
+    if s.starts_with('@') || beats.is_some()
+    {
+        let beats = number(beats.unwrap_or(&s[1..]))?;
+        if beats >= 1000.0
+        {
+            return Err(out_of_range());
+        }
+        // Back from Biel Mean Time to UTC
+        let micros = (beats * 86_400_000.0).round() as u64 + MICROS_PER_DAY - BMT_OFFSET_MICROS;
+        return Ok(Some(Time::from_micros(micros, TimeNotation::Swatch)));
+    }
+
+    if let Some(decimal) = s.strip_suffix("decimal")
+    {
+        let mut parts = [0u64; 3];
+        let sections: Vec<&str> = decimal.trim().split(':').collect();
+        if sections.len() > 3
+        {
+            return Err(ParseTimeError::TooManySections);
+        }
+        for (part, section) in parts.iter_mut().zip(sections)
+        {
+            *part = number(section)? as u64;
+        }
+
+        let [hours, minutes, seconds] = parts;
+        if hours >= 10 || minutes >= 100 || seconds >= 100
+        {
+            return Err(out_of_range());
+        }
+        // A decimal second is 0.864 seconds
+        let micros = (hours * 10_000 + minutes * 100 + seconds) * 864_000;
+        return Ok(Some(Time::from_micros(micros, TimeNotation::Decimal)));
+    }
+
+    let hours = ["hours", "hrs", "hr", "h"]
+        .iter()
+        .find_map(|suffix| s.strip_suffix(suffix))
+        .filter(|x| x.contains('.'));
+    if let Some(hours) = hours
+    {
+        let hours = number(hours)?;
+        if hours >= 24.0
+        {
+            return Err(out_of_range());
+        }
+        let micros = (hours * 3_600_000_000.0).round() as u64;
+        return Ok(Some(Time::from_micros(micros, TimeNotation::DecimalHours)));
+    }
+
+    Ok(None)
+}
+
+/// Parse a number from digits or words, up to 59 (e.g. '20', 'twenty-five').
+fn parse_number_word(s: &str) -> Option<u32>
+{
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [(&str, u32); 4] = [("twenty", 20), ("thirty", 30), ("forty", 40), ("fifty", 50)];
+
+    let s = s.trim();
+    if let Ok(x) = s.parse()
+    {
+        return Some(x);
+    }
+    if let Some(x) = ONES.iter().position(|&x| x == s)
+    {
+        return Some(x as u32);
+    }
+
+    let (tens, ones) = s.split_once(['-', ' ']).unwrap_or((s, "zero"));
+    let tens = TENS.iter().find(|(name, _)| *name == tens.trim())?.1;
+    let ones = ONES[..10].iter().position(|&x| x == ones.trim())? as u32;
+    Some(tens + ones)
+}
+
+/// Parse a time in words (e.g. 'noon', 'quarter past three', 'ten to midnight'),
+/// which is in the morning unless `pm` says otherwise.
+fn parse_words(s: &str, pm: Option<bool>) -> Option<Time>
+{
+    let s = ["o'clock", "o’clock", "oclock"]
+        .iter()
+        .fold(s.trim(), |s, suffix| {
+            s.strip_suffix(suffix).unwrap_or(s).trim()
+        });
+
+    let minutes = |s: &str| {
+        let s = s.trim().trim_start_matches("a ");
+        match s
+        {
+            "quarter" => Some(15),
+            "half" => Some(30),
+            _ =>
+            {
+                let s = ["minutes", "minute", "mins", "min"]
+                    .iter()
+                    .fold(s, |s, suffix| s.strip_suffix(suffix).unwrap_or(s));
+                parse_number_word(s)
+                    .filter(|x| (1..60).contains(x))
+                    .map(|x| x as i32)
+            }
+        }
+    };
+    let (offset, hour) = if let Some((m, hour)) =
+        s.split_once(" past ").or_else(|| s.split_once(" after "))
+    {
+        (minutes(m)?, hour)
+    }
+    else if let Some((m, hour)) = s.split_once(" to ").or_else(|| s.split_once(" till "))
+    {
+        (-minutes(m)?, hour)
+    }
+    else
+    {
+        (0, s)
+    };
+
+    let hour = match hour.trim()
+    {
+        "noon" | "midday" => 12,
+        "midnight" => 0,
+        hour => match (parse_number_word(hour)?, pm)
+        {
+            (12, Some(false)) => 0,
+            (hour @ 1..=11, Some(true)) => hour + 12,
+            (hour @ 1..=12, _) => hour,
+            _ => return None,
+        },
+    };
+
+    let total = (hour as i32 * 60 + offset).rem_euclid(24 * 60);
+    Some(Time {
+        kind: TimeNotation::TwelveHour,
+        hours: (total / 60) as u8,
+        minutes: (total % 60) as u8,
+        ..Default::default()
+    })
 }
 
 impl std::fmt::Display for Time
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
     {
+        let fraction = match self.micros
+        {
+            0 => String::new(),
+            micros if micros % 1000 == 0 => format!(".{:03}", micros / 1000),
+            micros => format!(".{micros:06}"),
+        };
+        let day = self.micros_of_day() as f64 / MICROS_PER_DAY as f64;
+
         match self.kind
         {
             TimeNotation::TwelveHour =>
             {
-                let (period, hours) = if self.hours == 0 || self.hours == 24
+                let (period, hours) = if self.hours == 0
                 {
                     ("AM", 12)
                 }
@@ -153,7 +725,7 @@ impl std::fmt::Display for Time
 
                 write!(
                     f,
-                    "{hours:02}:{:02}:{:02} {period}",
+                    "{hours:02}:{:02}:{:02}{fraction} {period}",
                     self.minutes, self.seconds
                 )
             }
@@ -161,10 +733,34 @@ impl std::fmt::Display for Time
             {
                 write!(
                     f,
-                    "{:02}:{:02}:{:02}",
+                    "{:02}:{:02}:{:02}{fraction}",
                     self.hours, self.minutes, self.seconds
                 )
             }
+            TimeNotation::Decimal =>
+            {
+                let seconds = (day * 100_000.0) as u32;
+                write!(
+                    f,
+                    "{}:{:02}:{:02} decimal",
+                    seconds / 10_000,
+                    seconds / 100 % 100,
+                    seconds % 100
+                )
+            }
+            TimeNotation::Swatch =>
+            {
+                let bmt = (self.micros_of_day() + BMT_OFFSET_MICROS) % MICROS_PER_DAY;
+                let centibeats = bmt * 100_000 / MICROS_PER_DAY;
+                write!(f, "@{:03}.{:02}", centibeats / 100, centibeats % 100)
+            }
+            // Truncated rather than rounded, so the end of the day is '23.99h'
+            // rather than '24.00h', which wouldn't parse
+            TimeNotation::DecimalHours =>
+            {
+                let centihours = self.micros_of_day() / 36_000_000;
+                write!(f, "{}.{:02}h", centihours / 100, centihours % 100)
+            }
         }
     }
 }
@@ -191,6 +787,12 @@ impl Time
         self
     }
 
+    pub fn to_notation(&mut self, kind: TimeNotation) -> &mut Self
+    {
+        self.kind = kind;
+        self
+    }
+
     pub fn to_opposite(&mut self) -> &mut Self
     {
         match self.kind
@@ -199,6 +801,98 @@ impl Time
             _ => self.to_24(),
         }
     }
+
+    pub fn kind(&self) -> TimeNotation { self.kind }
+
+    pub fn as_naive(&self) -> NaiveTime
+    {
+        NaiveTime::from_hms_micro_opt(
+            self.hours.into(),
+            self.minutes.into(),
+            self.seconds.into(),
+            self.micros,
+        )
+        .expect("times are always in range")
+    }
+
+    pub fn from_naive(time: NaiveTime, kind: TimeNotation) -> Self
+    {
+        Self {
+            kind,
+            hours: time.hour() as u8,
+            minutes: time.minute() as u8,
+            seconds: time.second() as u8,
+            // Leap seconds are counted as part of the previous second
+            micros: (time.nanosecond() / 1000).min(999_999),
+        }
+    }
+
+    /// The number of microseconds since midnight.
+    pub fn micros_of_day(&self) -> u64
+    {
+        (u64::from(self.hours) * 3600 + u64::from(self.minutes) * 60 + u64::from(self.seconds))
+            * 1_000_000
+            + u64::from(self.micros)
+    }
+
+    /// The time `micros` microseconds after midnight, wrapping around to the
+    /// next day.
+    pub fn from_micros(micros: u64, kind: TimeNotation) -> Self
+    {
+        let micros = micros % MICROS_PER_DAY;
+        let seconds = micros / 1_000_000;
+        Self {
+            kind,
+            hours: (seconds / 3600) as u8,
+            minutes: (seconds / 60 % 60) as u8,
+            seconds: (seconds % 60) as u8,
+            micros: (micros % 1_000_000) as u32,
+        }
+    }
+
+    /// Add `duration`, wrapping around midnight, along with the number of days
+    /// it moved by (negative for earlier days).
+    pub fn overflowing_add(self, duration: Duration) -> (Self, i64)
+    {
+        let (time, seconds) = self.as_naive().overflowing_add_signed(duration);
+        (Self::from_naive(time, self.kind), seconds / 86400)
+    }
+
+    /// How long it is from this time until `other`, going past midnight if
+    /// `other` is earlier (e.g. 22:00 until 06:00 is 8 hours).
+    pub fn until(self, other: Self) -> Duration
+    {
+        let duration = other - self;
+        if duration < Duration::zero()
+        {
+            duration + Duration::days(1)
+        }
+        else
+        {
+            duration
+        }
+    }
+}
+
+impl Add<Duration> for Time
+{
+    type Output = Self;
+
+    fn add(self, duration: Duration) -> Self { self.overflowing_add(duration).0 }
+}
+
+impl Sub<Duration> for Time
+{
+    type Output = Self;
+
+    fn sub(self, duration: Duration) -> Self { self.overflowing_add(-duration).0 }
+}
+
+impl Sub for Time
+{
+    type Output = Duration;
+
+    fn sub(self, other: Self) -> Duration { self.as_naive() - other.as_naive() }
 }
 
 #[cfg(test)]
@@ -216,6 +910,7 @@ pub mod test
             hours: 12,
             minutes: 0,
             seconds: 0,
+            micros: 0,
         };
 
         assert_eq!(t, time);
@@ -230,6 +925,7 @@ pub mod test
             hours: 6,
             minutes: 40,
             seconds: 0,
+            micros: 0,
         };
 
         assert_eq!(test, time);
@@ -244,6 +940,7 @@ pub mod test
             hours: 14,
             minutes: 50,
             seconds: 11,
+            micros: 0,
         };
 
         assert_eq!(test, time);
@@ -258,6 +955,7 @@ pub mod test
             hours: 0,
             minutes: 50,
             seconds: 11,
+            micros: 0,
         };
 
         assert_eq!(test, time);
@@ -302,6 +1000,7 @@ pub mod test
             hours: 6,
             minutes: 40,
             seconds: 0,
+            micros: 0,
         };
 
         assert_eq!(test, time);
@@ -317,6 +1016,7 @@ pub mod test
             hours: 18,
             minutes: 45,
             seconds: 5,
+            micros: 0,
         };
 
         assert_eq!(test, time);
@@ -332,6 +1032,7 @@ pub mod test
             hours: 6,
             minutes: 45,
             seconds: 5,
+            micros: 0,
         };
 
         assert_eq!(test, time);
@@ -340,15 +1041,207 @@ pub mod test
     #[test]
     fn test_convert_24h_to_12h_pm()
     {
-        let mut test = Time::from_str("24:0:05").unwrap();
+        let mut test = Time::from_str("23:0:05").unwrap();
         test.to_12();
         let time = Time {
             kind: TimeNotation::TwelveHour,
-            hours: 24,
+            hours: 23,
             minutes: 0,
             seconds: 5,
+            micros: 0,
         };
 
         assert_eq!(test, time);
     }
+
+    #[test]
+    fn test_from_str_out_of_range()
+    {
+        assert!(matches!(
+            Time::from_str("24:0:05"),
+            Err(ParseTimeError::HourOutOfRange(24))
+        ));
+        assert!(matches!(
+            Time::from_str("12:99"),
+            Err(ParseTimeError::MinuteOutOfRange(99))
+        ));
+        assert!(matches!(
+            Time::from_str("12:00:60"),
+            Err(ParseTimeError::SecondOutOfRange(60))
+        ));
+        assert!(matches!(
+            Time::from_str("13pm"),
+            Err(ParseTimeError::InvalidTwelveHourHour(13))
+        ));
+        assert!(matches!(
+            Time::from_str("0am"),
+            Err(ParseTimeError::InvalidTwelveHourHour(0))
+        ));
+    }
+
+    #[test]
+    fn test_from_str_lenient()
+    {
+        let test = Time::from_str_lenient("24:0:05").unwrap();
+        assert_eq!(test.time.to_string(), "00:00:05");
+        assert_eq!(test.days, 1);
+        assert!(test.normalised);
+
+        let test = Time::from_str_lenient("13pm").unwrap();
+        assert_eq!(test.time.to_string(), "01:00:00 PM");
+        assert_eq!(test.days, 0);
+        assert!(test.normalised);
+
+        let test = Time::from_str_lenient("10:30").unwrap();
+        assert!(!test.normalised);
+
+        assert_eq!(
+            run("23:60".to_string(), true, None),
+            "00:00:00 -> 12:00:00 AM\nNormalised from '23:60', the next day"
+        );
+    }
+
+    #[test]
+    fn test_from_str_other_formats()
+    {
+        for (input, expected) in [
+            ("1430", "14:30:00"),
+            ("1430h", "14:30:00"),
+            ("930", "09:30:00"),
+            ("2.30pm", "02:30:00 PM"),
+            ("2.30 p.m.", "02:30:00 PM"),
+            ("9.15 a.m.", "09:15:00 AM"),
+            ("14h30", "14:30:00"),
+            ("14h", "14:00:00"),
+            ("noon", "12:00:00 PM"),
+            ("midnight", "12:00:00 AM"),
+            ("quarter past three", "03:15:00 AM"),
+            ("half past seven pm", "07:30:00 PM"),
+            ("twenty-five to four p.m.", "03:35:00 PM"),
+            ("ten to midnight", "11:50:00 PM"),
+            ("three o'clock", "03:00:00 AM"),
+            ("12:00:00.250", "12:00:00.250"),
+            ("12:00:00.5 pm", "12:00:00.500 PM"),
+        ]
+        {
+            let time = Time::from_str(input).unwrap();
+            assert_eq!(time.to_string(), expected, "{input}");
+            // Each form should round-trip through Display
+            assert_eq!(Time::from_str(&time.to_string()).unwrap(), time, "{input}");
+        }
+
+        assert!(matches!(
+            Time::from_str("quarter past thirteen"),
+            Err(ParseTimeError::Unrecognised(_))
+        ));
+        assert!(Time::from_str("12:00.5").is_err());
+        assert!(Time::from_str("12:00:00.x").is_err());
+    }
+
+    #[test]
+    fn test_arithmetic()
+    {
+        let time = Time::from_str("14:30").unwrap();
+        assert_eq!((time + Duration::minutes(165)).to_string(), "17:15:00");
+        assert_eq!((time - Duration::hours(15)).to_string(), "23:30:00");
+        assert_eq!(
+            time.overflowing_add(Duration::hours(10)),
+            (Time::from_str("00:30").unwrap(), 1)
+        );
+
+        let start = Time::from_str("09:15").unwrap();
+        let end = Time::from_str("17:40").unwrap();
+        assert_eq!(end - start, Duration::minutes(505));
+        assert_eq!(end.until(start), Duration::minutes(935));
+    }
+
+    #[test]
+    fn test_format_duration()
+    {
+        assert_eq!(
+            format_duration(Duration::milliseconds(4295500)),
+            "1h 11m 36s"
+        );
+        assert_eq!(format_duration(Duration::milliseconds(-250)), "-250ms");
+        // The extremes don't overflow
+        assert!(format_duration(Duration::milliseconds(i64::MAX)).ends_with('s'));
+        assert!(format_duration(Duration::milliseconds(-i64::MAX)).starts_with('-'));
+    }
+
+    #[test]
+    fn test_sub_second()
+    {
+        let time = Time::from_str("12:00:00.000250").unwrap();
+        assert_eq!(time.micros, 250);
+        assert_eq!(time.to_string(), "12:00:00.000250");
+        assert_eq!(
+            Time::from_str("12:00:00.25").unwrap().to_string(),
+            "12:00:00.250"
+        );
+    }
+
+    #[test]
+    fn test_notations()
+    {
+        for (input, kind, expected) in [
+            ("12:00", TimeNotation::Decimal, "5:00:00 decimal"),
+            ("18:00", TimeNotation::Decimal, "7:50:00 decimal"),
+            ("12:00", TimeNotation::Swatch, "@541.66"),
+            ("23:00", TimeNotation::Swatch, "@000.00"),
+            ("01:00", TimeNotation::Swatch, "@083.33"),
+            ("7:45", TimeNotation::DecimalHours, "7.75h"),
+        ]
+        {
+            let mut time = Time::from_str(input).unwrap();
+            assert_eq!(time.to_notation(kind).to_string(), expected);
+        }
+
+        assert_eq!(
+            Time::from_str("7.75h").unwrap().to_24().to_string(),
+            "07:45:00"
+        );
+        assert_eq!(
+            Time::from_str("5:00:00 decimal")
+                .unwrap()
+                .to_24()
+                .to_string(),
+            "12:00:00"
+        );
+        assert_eq!(
+            Time::from_str("@500").unwrap().to_24().to_string(),
+            "11:00:00"
+        );
+        assert_eq!(
+            Time::from_str("250 .beats").unwrap().to_24().to_string(),
+            "05:00:00"
+        );
+
+        // The end of the day round-trips
+        let mut time = Time::from_str("23:59:59").unwrap();
+        let hours = time.to_notation(TimeNotation::DecimalHours).to_string();
+        assert_eq!(hours, "23.99h");
+        assert_eq!(
+            Time::from_str(&hours).unwrap().to_24().to_string(),
+            "23:59:24"
+        );
+        assert!(matches!(
+            Time::from_str("@1000"),
+            Err(ParseTimeError::NotationOutOfRange(_))
+        ));
+        assert!(Time::from_str("10:00:00 decimal").is_err());
+
+        assert_eq!(
+            run("9:30".to_string(), false, Some("decimal hours".to_string())),
+            "09:30:00 -> 9.50h"
+        );
+        assert!(run("9:30".to_string(), false, Some("martian".to_string())).starts_with("Error"));
+        assert!(matches!(
+            convert("25:00".to_string(), false, None, Locale::English),
+            Reply::Error(_)
+        ));
+        assert_eq!(
+            convert("24:00".to_string(), true, None, Locale::German).text(Locale::German),
+            "00:00:00 -> 12:00:00 AM\nNormalisiert aus '24:00', am nächsten Tag"
+        );
+    }
 }
@@ -0,0 +1,258 @@
+use crate::{
+    date::{Date, DateOrder, ParseDateError},
+    i18n::Locale,
+    reply::Reply,
+    spec::{OptionSpec, SubcommandSpec},
+    time::{ParseTimeError, Time},
+    timezone::{TimeZoneError, Zone, ZonedTime},
+};
+use chrono::{TimeZone, Utc};
+use chrono_tz::Tz;
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `timestamp` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "timestamp",
+    "Make Discord timestamps, which show in each reader's own time zone.",
+)
+.details("Make Discord timestamps in every format. Discord shows them in each reader's own time zone")
+.options(&[
+    OptionSpec::string("time", "The time, optionally with a zone (e.g. '3pm', '14:30 Europe/London').")
+        .details("The time, optionally followed by a zone (e.g. '3pm', '3pm EST', '14:30 Europe/London')"),
+    OptionSpec::string("date", "The date (e.g. '2023-07-15', '15 July 2023'). Defaults to today.")
+        .optional()
+        .details("The date (e.g. '2023-07-15', '15/07/2023', '15 July 2023'). Numeric dates are read as day/month/year unless that's impossible. Defaults to today"),
+    OptionSpec::string("zone", "The time zone (e.g. 'EST', 'Tokyo', 'UTC+5:30'). Defaults to your zone, or UTC.")
+        .optional()
+        .details("The time zone, if it isn't given with the time (e.g. 'EST', 'Tokyo', 'UTC+5:30'). Defaults to your preferred zone, or UTC"),
+]);
+
+pub fn run(time: String, date: Option<String>, zone: Option<String>) -> String
+{
+    convert(time, date, zone, Locale::English).to_string()
+}
+
+/// Like [`run`], but tells errors apart from the timestamps, written in
+/// `locale`.
+pub fn convert(time: String, date: Option<String>, zone: Option<String>, locale: Locale) -> Reply
+{
+    match Timestamp::parse(&time, date.as_deref(), zone.as_deref())
+    {
+        Ok(timestamp) => Reply::Text(timestamp.text(locale)),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum TimestampError
+{
+    Time(#[from] ParseTimeError),
+    Zone(#[from] TimeZoneError),
+    Date(#[from] ParseDateError),
+}
+
+impl TimestampError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::Time(e) => e.text(locale),
+            Self::Zone(e) => e.text(locale),
+            Self::Date(e) => e.text(locale),
+        }
+    }
+}
+
+/// The ways Discord can show a timestamp.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimestampStyle
+{
+    ShortTime,
+    LongTime,
+    ShortDate,
+    LongDate,
+    ShortDateTime,
+    LongDateTime,
+    Relative,
+}
+
+impl TimestampStyle
+{
+    pub const ALL: [Self; 7] = [
+        Self::ShortTime,
+        Self::LongTime,
+        Self::ShortDate,
+        Self::LongDate,
+        Self::ShortDateTime,
+        Self::LongDateTime,
+        Self::Relative,
+    ];
+
+    /// The letter Discord uses for the style.
+    pub fn letter(&self) -> char
+    {
+        match self
+        {
+            Self::ShortTime => 't',
+            Self::LongTime => 'T',
+            Self::ShortDate => 'd',
+            Self::LongDate => 'D',
+            Self::ShortDateTime => 'f',
+            Self::LongDateTime => 'F',
+            Self::Relative => 'R',
+        }
+    }
+
+    /// The style's name in `locale` (e.g. 'Short time').
+    pub fn name(&self, locale: Locale) -> String
+    {
+        locale.text(&format!("timestamp.{}", self.key()), &[])
+    }
+
+    /// What the style looks like in `locale` (e.g. '16:20' for a short time).
+    pub fn example(&self, locale: Locale) -> String
+    {
+        locale.text(&format!("timestamp.{}.example", self.key()), &[])
+    }
+
+    /// The style's name in catalogue keys.
+    fn key(&self) -> &'static str
+    {
+        match self
+        {
+            Self::ShortTime => "short-time",
+            Self::LongTime => "long-time",
+            Self::ShortDate => "short-date",
+            Self::LongDate => "long-date",
+            Self::ShortDateTime => "short-date-time",
+            Self::LongDateTime => "long-date-time",
+            Self::Relative => "relative",
+        }
+    }
+}
+
+impl fmt::Display for TimestampStyle
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}
+
+/// A moment in time, as seconds since the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Timestamp(pub i64);
+
+impl Timestamp
+{
+    /// Parse a time, along with an optional date and zone. The zone may also
+    /// be given with the time (e.g. '3pm EST'), and is UTC if missing. The
+    /// date is today's date in the zone if missing.
+    pub fn parse(time: &str, date: Option<&str>, zone: Option<&str>)
+        -> Result<Self, TimestampError>
+    {
+        let time = match zone
+        {
+            Some(zone) => ZonedTime {
+                time: Time::from_str(time)?,
+                zone: Zone::from_str(zone)?,
+            },
+            None => match ZonedTime::from_str(time)
+            {
+                Ok(x) => x,
+                Err(TimeZoneError::MissingZone) => ZonedTime {
+                    time: Time::from_str(time)?,
+                    zone: Zone::Region(Tz::UTC),
+                },
+                Err(e) => return Err(e.into()),
+            },
+        };
+        let date = match date
+        {
+            Some(date) => Date::parse(date, DateOrder::default())?
+                .datetime
+                .date_naive(),
+            None => time.zone.today(),
+        };
+
+        Ok(Self(Utc.from_utc_datetime(&time.to_utc(date)?).timestamp()))
+    }
+
+    /// The markup Discord shows in `style`.
+    pub fn tag(&self, style: TimestampStyle) -> String
+    {
+        format!("<t:{}:{}>", self.0, style.letter())
+    }
+
+    /// The markup for every style, with examples in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        TimestampStyle::ALL
+            .iter()
+            .map(|&style| {
+                locale.text(
+                    "timestamp.line",
+                    &[
+                        ("style", &style.name(locale)),
+                        ("example", &style.example(locale)),
+                        ("tag", &self.tag(style)),
+                    ],
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for Timestamp
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.text(Locale::English))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn test_parse()
+    {
+        assert_eq!(
+            Timestamp::parse("15:00", Some("15 July 2023"), None).unwrap(),
+            Timestamp(1689433200)
+        );
+        assert_eq!(
+            Timestamp::parse("3pm EST", Some("2023-07-15"), None).unwrap(),
+            Timestamp(1689451200)
+        );
+        assert_eq!(
+            Timestamp::parse("3pm", Some("2023-07-15"), Some("America/New_York")).unwrap(),
+            Timestamp(1689447600)
+        );
+        assert!(Timestamp::parse("3pm", Some("31/02/2023"), None).is_err());
+        assert!(Timestamp::parse("3pm Narnia", None, None).is_err());
+    }
+
+    #[test]
+    fn test_tags()
+    {
+        let timestamp = Timestamp(1689433200);
+        assert_eq!(
+            timestamp.tag(TimestampStyle::LongDateTime),
+            "<t:1689433200:F>"
+        );
+
+        let text = timestamp.to_string();
+        assert_eq!(text.lines().count(), 7);
+        assert!(
+            text.contains("Relative (e.g. '2 months ago'): `<t:1689433200:R>` -> <t:1689433200:R>")
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use crate::{
+    i18n::Locale,
+    reply::{Conversion, Reply},
+    spec::{OptionSpec, SubcommandSpec},
+    time::{ParseTimeError, Time},
+};
+use chrono::{Duration, FixedOffset, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::{Tz, TZ_VARIANTS};
+use std::{fmt, str::FromStr};
+use thiserror::Error;
+
+/// The `timezone` subcommand.
+pub const SUBCOMMAND: SubcommandSpec = SubcommandSpec::new(
+    "timezone",
+    "Convert a time from one time zone to another, accounting for daylight saving time.",
+)
+.details("Convert a time from one time zone to another, accounting for daylight saving time. Uses today's date")
+.options(&[
+    OptionSpec::string("time", "Time and zone (e.g. '3pm EST', '14:30 Europe/London', '9am Tokyo').")
+        .details("The time, followed by an IANA zone name, abbreviation, offset, or city (e.g. '3pm EST', '14:30 Europe/London', '9am Tokyo')"),
+    OptionSpec::string("target", "The zone to target. (e.g 'Asia/Tokyo', 'PT', 'Berlin'). Defaults to your zone.")
+        .optional()
+        .details("The zone to target. (e.g 'Asia/Tokyo', 'PT' [US Pacific], 'Berlin', 'UTC+5:30'). Defaults to your preferred zone"),
+]);
+
+pub fn run(time: String, target: String) -> String
+{
+    convert(time, target, Locale::English).to_string()
+}
+
+/// Like [`run`], but keeps the result's structure, written in `locale`.
+pub fn convert(time: String, target: String, locale: Locale) -> Reply
+{
+    let time = match ZonedTime::from_str(&time)
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+    let target = match Zone::from_str(&target)
+    {
+        Ok(x) => x,
+        Err(e) => return Reply::Error(e.text(locale)),
+    };
+
+    match time.convert(time.zone.today(), target, locale)
+    {
+        Ok(x) => Reply::Conversion(x),
+        Err(e) => Reply::Error(e.text(locale)),
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{}", self.text(Locale::English))]
+pub enum TimeZoneError
+{
+    Time(#[from] ParseTimeError),
+    UnknownZone(String),
+    MissingZone,
+    SkippedTime
+    {
+        time: String,
+        zone: String,
+        date: NaiveDate,
+    },
+}
+
+impl TimeZoneError
+{
+    /// The error as text in `locale`.
+    pub fn text(&self, locale: Locale) -> String
+    {
+        match self
+        {
+            Self::Time(e) => e.text(locale),
+            Self::UnknownZone(zone) => locale.text("error.timezone.unknown", &[("zone", zone)]),
+            Self::MissingZone => locale.text("error.timezone.missing", &[]),
+            Self::SkippedTime { time, zone, date } => locale.text(
+                "error.timezone.skipped",
+                &[("time", time), ("zone", zone), ("date", &date.to_string())],
+            ),
+        }
+    }
+}
+
+/// Abbreviations for a single offset. These are the North American meanings
+/// where an abbreviation is shared (e.g. 'CST' is not China Standard Time).
+const FIXED_ABBREVIATIONS: &[(&str, i32)] = &[
+    ("UTC", 0),
+    ("GMT", 0),
+    ("WET", 0),
+    ("WEST", 60),
+    ("BST", 60),
+    ("CET", 60),
+    ("CEST", 120),
+    ("EET", 120),
+    ("EEST", 180),
+    ("MSK", 180),
+    ("IST", 330),
+    ("SGT", 480),
+    ("HKT", 480),
+    ("AWST", 480),
+    ("JST", 540),
+    ("KST", 540),
+    ("ACST", 570),
+    ("ACDT", 630),
+    ("AEST", 600),
+    ("AEDT", 660),
+    ("NZST", 720),
+    ("NZDT", 780),
+    ("NST", -210),
+    ("NDT", -150),
+    ("AST", -240),
+    ("ADT", -180),
+    ("EST", -300),
+    ("EDT", -240),
+    ("CST", -360),
+    ("CDT", -300),
+    ("MST", -420),
+    ("MDT", -360),
+    ("PST", -480),
+    ("PDT", -420),
+    ("AKST", -540),
+    ("AKDT", -480),
+    ("HST", -600),
+];
+
+/// Abbreviations that don't say whether daylight saving time is in effect,
+/// so they follow the region's rules.
+const REGION_ABBREVIATIONS: &[(&str, Tz)] = &[
+    ("ET", Tz::America__New_York),
+    ("CT", Tz::America__Chicago),
+    ("MT", Tz::America__Denver),
+    ("PT", Tz::America__Los_Angeles),
+    ("AKT", Tz::America__Anchorage),
+    ("AET", Tz::Australia__Sydney),
+    ("NZT", Tz::Pacific__Auckland),
+];
+
+/// Cities that aren't in the name of their zone.
+const CITIES: &[(&str, Tz)] = &[
+    ("washington", Tz::America__New_York),
+    ("boston", Tz::America__New_York),
+    ("miami", Tz::America__New_York),
+    ("atlanta", Tz::America__New_York),
+    ("montreal", Tz::America__Toronto),
+    ("ottawa", Tz::America__Toronto),
+    ("dallas", Tz::America__Chicago),
+    ("houston", Tz::America__Chicago),
+    ("austin", Tz::America__Chicago),
+    ("san francisco", Tz::America__Los_Angeles),
+    ("seattle", Tz::America__Los_Angeles),
+    ("las vegas", Tz::America__Los_Angeles),
+    ("salt lake city", Tz::America__Denver),
+    ("munich", Tz::Europe__Berlin),
+    ("frankfurt", Tz::Europe__Berlin),
+    ("hamburg", Tz::Europe__Berlin),
+    ("milan", Tz::Europe__Rome),
+    ("barcelona", Tz::Europe__Madrid),
+    ("geneva", Tz::Europe__Zurich),
+    ("saint petersburg", Tz::Europe__Moscow),
+    ("st petersburg", Tz::Europe__Moscow),
+    ("mumbai", Tz::Asia__Kolkata),
+    ("delhi", Tz::Asia__Kolkata),
+    ("new delhi", Tz::Asia__Kolkata),
+    ("bangalore", Tz::Asia__Kolkata),
+    ("beijing", Tz::Asia__Shanghai),
+    ("osaka", Tz::Asia__Tokyo),
+    ("kyoto", Tz::Asia__Tokyo),
+    ("canberra", Tz::Australia__Sydney),
+    ("wellington", Tz::Pacific__Auckland),
+];
+
+/// A time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Zone
+{
+    /// A region, which follows its own daylight saving time rules
+    Region(Tz),
+
+    /// A fixed offset from UTC, named if it came from an abbreviation
+    Fixed
+    {
+        name: Option<&'static str>,
+        offset: FixedOffset,
+    },
+}
+
+impl Zone
+{
+    /// Today's date in this zone.
+    pub fn today(self) -> NaiveDate { self.localise(Utc::now().naive_utc()).0.date() }
+
+    /// Convert a local date and time in this zone to UTC. Times that happen
+    /// twice when the clocks go back are taken as the first one.
+    pub fn to_utc(self, local: NaiveDateTime) -> Result<NaiveDateTime, TimeZoneError>
+    {
+        match self
+        {
+            Self::Region(tz) => match tz.from_local_datetime(&local)
+            {
+                LocalResult::Single(x) | LocalResult::Ambiguous(x, _) => Ok(x.naive_utc()),
+                LocalResult::None => Err(TimeZoneError::SkippedTime {
+                    time: local.time().to_string(),
+                    zone: tz.name().to_string(),
+                    date: local.date(),
+                }),
+            },
+            Self::Fixed { offset, .. } =>
+            {
+                Ok(local - Duration::seconds(offset.local_minus_utc().into()))
+            }
+        }
+    }
+
+    /// Convert a UTC date and time to the local one in this zone, along with
+    /// the abbreviation in effect at the time.
+    pub fn localise(self, utc: NaiveDateTime) -> (NaiveDateTime, String)
+    {
+        match self
+        {
+            Self::Region(tz) =>
+            {
+                let local = tz.from_utc_datetime(&utc);
+                (local.naive_local(), local.format("%Z").to_string())
+            }
+            Self::Fixed { offset, .. } => (
+                utc + Duration::seconds(offset.local_minus_utc().into()),
+                self.to_string(),
+            ),
+        }
+    }
+
+    /// Describe the zone with the abbreviation in effect at the time.
+    pub(crate) fn describe(self, abbreviation: &str) -> String
+    {
+        match self
+        {
+            Self::Region(tz) if tz.name() == abbreviation => abbreviation.to_string(),
+            Self::Region(tz) => format!("{abbreviation} [{}]", tz.name()),
+            Self::Fixed {
+                name: Some(name),
+                offset,
+            } => format!("{name} [UTC{offset}]"),
+            Self::Fixed { name: None, .. } => self.to_string(),
+        }
+    }
+}
+
+/// Parse an offset from UTC (e.g. 'UTC+5:30', 'GMT-3', '+0100').
+fn parse_offset(s: &str) -> Option<FixedOffset>
+{
+    let s = s.to_lowercase();
+    let s = s
+        .strip_prefix("utc")
+        .or_else(|| s.strip_prefix("gmt"))
+        .unwrap_or(&s)
+        .trim();
+
+    let (sign, s) = match s.chars().next()?
+    {
+        '+' => (1, &s[1..]),
+        '-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let (hours, minutes) = match s.split_once(':')
+    {
+        Some(x) => x,
+        None if s.len() > 2 && s.bytes().all(|b| b.is_ascii_digit()) => s.split_at(s.len() - 2),
+        None => (s, "0"),
+    };
+    let hours: i32 = hours.trim().parse().ok()?;
+    let minutes: i32 = minutes.trim().parse().ok()?;
+    if minutes >= 60
+    {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+impl FromStr for Zone
+{
+    type Err = TimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim();
+        if s.is_empty()
+        {
+            return Err(TimeZoneError::MissingZone);
+        }
+
+        if let Some(&(name, minutes)) = FIXED_ABBREVIATIONS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        {
+            return Ok(Self::Fixed {
+                name: Some(name),
+                offset: FixedOffset::east_opt(minutes * 60).expect("offsets are in range"),
+            });
+        }
+        if let Some(offset) = parse_offset(s)
+        {
+            return Ok(Self::Fixed { name: None, offset });
+        }
+        if let Some(&(_, tz)) = REGION_ABBREVIATIONS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(s))
+        {
+            return Ok(Self::Region(tz));
+        }
+
+        // IANA names (e.g. 'America/New_York'), then the city in them
+        // (e.g. 'new york'), then other cities
+        let name = s.replace(' ', "_");
+        let city = |tz: &&Tz| {
+            tz.name()
+                .rsplit('/')
+                .next()
+                .is_some_and(|city| city.eq_ignore_ascii_case(&name))
+        };
+        TZ_VARIANTS
+            .iter()
+            .find(|tz| tz.name().eq_ignore_ascii_case(&name))
+            .or_else(|| TZ_VARIANTS.iter().find(city))
+            .or_else(|| {
+                CITIES
+                    .iter()
+                    .find(|(city, _)| city.eq_ignore_ascii_case(s))
+                    .map(|(_, tz)| tz)
+            })
+            .map(|&tz| Self::Region(tz))
+            .ok_or_else(|| TimeZoneError::UnknownZone(s.to_string()))
+    }
+}
+
+impl fmt::Display for Zone
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self
+        {
+            Self::Region(tz) => write!(f, "{}", tz.name()),
+            Self::Fixed {
+                name: Some(name), ..
+            } => write!(f, "{name}"),
+            Self::Fixed { name: None, offset } => write!(f, "UTC{offset}"),
+        }
+    }
+}
+
+/// A time of day in a time zone (e.g. '3pm EST', '14:30 Europe/London').
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZonedTime
+{
+    pub time: Time,
+    pub zone: Zone,
+}
+
+impl FromStr for ZonedTime
+{
+    type Err = TimeZoneError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let words: Vec<&str> = s.split_whitespace().collect();
+
+        // Take the longest time that parses, as the time may have spaces in it
+        // (e.g. '3 pm') as well as the zone (e.g. 'New York')
+        let split = (1..words.len())
+            .rev()
+            .find(|&i| Time::from_str(&words[..i].join(" ")).is_ok());
+        match split
+        {
+            Some(i) => Ok(Self {
+                time: Time::from_str(&words[..i].join(" "))?,
+                zone: Zone::from_str(&words[i..].join(" "))?,
+            }),
+            None =>
+            {
+                Time::from_str(s)?;
+                Err(TimeZoneError::MissingZone)
+            }
+        }
+    }
+}
+
+impl ZonedTime
+{
+    /// The UTC date and time of this time on `date`.
+    pub fn to_utc(&self, date: NaiveDate) -> Result<NaiveDateTime, TimeZoneError>
+    {
+        self.zone.to_utc(date.and_time(self.time.as_naive()))
+    }
+
+    /// Convert the time on `date` to `target`, written in `locale`.
+    pub fn convert(
+        &self,
+        date: NaiveDate,
+        target: Zone,
+        locale: Locale,
+    ) -> Result<Conversion, TimeZoneError>
+    {
+        let utc = self.to_utc(date)?;
+        let (_, abbreviation) = self.zone.localise(utc);
+        let (converted, target_abbreviation) = target.localise(utc);
+
+        let day = match (converted.date() - date).num_days()
+        {
+            0 => String::new(),
+            1 => locale.text("day-offset.next", &[]),
+            -1 => locale.text("day-offset.previous", &[]),
+            days => locale.text("day-offset.days", &[("days", &format!("{days:+}"))]),
+        };
+
+        Ok(Conversion {
+            input: format!("{} {}", self.time, self.zone.describe(&abbreviation)),
+            output: format!(
+                "{} {}{day}",
+                Time::from_naive(converted.time(), self.time.kind()),
+                target.describe(&target_abbreviation)
+            ),
+            ..Default::default()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate
+    {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn convert(time: &str, target: &str, date: NaiveDate) -> String
+    {
+        ZonedTime::from_str(time)
+            .unwrap()
+            .convert(date, Zone::from_str(target).unwrap(), Locale::English)
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn test_zone_from_str()
+    {
+        assert_eq!(
+            Zone::from_str("america/new_york").unwrap(),
+            Zone::Region(Tz::America__New_York)
+        );
+        assert_eq!(
+            Zone::from_str("New York").unwrap(),
+            Zone::Region(Tz::America__New_York)
+        );
+        assert_eq!(
+            Zone::from_str("mumbai").unwrap(),
+            Zone::Region(Tz::Asia__Kolkata)
+        );
+        assert_eq!(
+            Zone::from_str("pt").unwrap(),
+            Zone::Region(Tz::America__Los_Angeles)
+        );
+        assert_eq!(Zone::from_str("UTC+5:30").unwrap().to_string(), "UTC+05:30");
+        assert_eq!(Zone::from_str("gmt-3").unwrap().to_string(), "UTC-03:00");
+        assert_eq!(Zone::from_str("est").unwrap().to_string(), "EST");
+        assert!(Zone::from_str("Narnia").is_err());
+    }
+
+    #[test]
+    fn test_zoned_time_from_str()
+    {
+        let time = ZonedTime::from_str("3 pm New York").unwrap();
+        assert_eq!(time.time.to_string(), "03:00:00 PM");
+        assert_eq!(time.zone, Zone::Region(Tz::America__New_York));
+
+        assert!(matches!(
+            ZonedTime::from_str("3pm"),
+            Err(TimeZoneError::MissingZone)
+        ));
+        assert!(matches!(
+            ZonedTime::from_str("3pm Narnia"),
+            Err(TimeZoneError::UnknownZone(_))
+        ));
+    }
+
+    #[test]
+    fn test_convert_dst()
+    {
+        assert_eq!(
+            convert("3pm ET", "Tokyo", date(2023, 1, 15)),
+            "03:00:00 PM EST [America/New_York] -> 05:00:00 AM JST [Asia/Tokyo] (next day)"
+        );
+        assert_eq!(
+            convert("3pm ET", "Tokyo", date(2023, 7, 15)),
+            "03:00:00 PM EDT [America/New_York] -> 04:00:00 AM JST [Asia/Tokyo] (next day)"
+        );
+        assert_eq!(
+            convert("14:30 Europe/London", "PT", date(2023, 7, 15)),
+            "14:30:00 BST [Europe/London] -> 06:30:00 PDT [America/Los_Angeles]"
+        );
+    }
+
+    #[test]
+    fn test_convert_fixed()
+    {
+        assert_eq!(
+            convert("1am EST", "UTC-8", date(2023, 7, 15)),
+            "01:00:00 AM EST [UTC-05:00] -> 10:00:00 PM UTC-08:00 (previous day)"
+        );
+    }
+
+    #[test]
+    fn test_convert_skipped()
+    {
+        let time = ZonedTime::from_str("2:30am America/New_York").unwrap();
+        assert!(matches!(
+            time.convert(
+                date(2023, 3, 12),
+                Zone::from_str("UTC").unwrap(),
+                Locale::English
+            ),
+            Err(TimeZoneError::SkippedTime { .. })
+        ));
+    }
+}
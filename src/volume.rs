@@ -0,0 +1,100 @@
+use crate::{
+    i18n::Locale,
+    quantity::{LinearUnit, ParseUnitError, Quantity},
+};
+use std::{fmt, str::FromStr};
+
+/// A volume, stored in litres.
+pub type Volume = Quantity<VolumeUnit>;
+
+/// Customary units are the US ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VolumeUnit
+{
+    Millilitre,
+    Litre,
+    Teaspoon,
+    Tablespoon,
+    FluidOunce,
+    Cup,
+    Pint,
+    Quart,
+    Gallon,
+}
+
+impl LinearUnit for VolumeUnit
+{
+    const ALL: &'static [Self] = &[
+        Self::Millilitre,
+        Self::Litre,
+        Self::Teaspoon,
+        Self::Tablespoon,
+        Self::FluidOunce,
+        Self::Cup,
+        Self::Pint,
+        Self::Quart,
+        Self::Gallon,
+    ];
+
+    fn factor(&self) -> f64
+    {
+        match self
+        {
+            Self::Millilitre => 1e-3,
+            Self::Litre => 1.0,
+            Self::Teaspoon => 0.00492892159375,
+            Self::Tablespoon => 0.01478676478125,
+            Self::FluidOunce => 0.0295735295625,
+            Self::Cup => 0.2365882365,
+            Self::Pint => 0.473176473,
+            Self::Quart => 0.946352946,
+            Self::Gallon => 3.785411784,
+        }
+    }
+
+    fn symbol(&self) -> &'static str
+    {
+        match self
+        {
+            Self::Millilitre => "mL",
+            Self::Litre => "L",
+            Self::Teaspoon => "tsp",
+            Self::Tablespoon => "tbsp",
+            Self::FluidOunce => "fl oz",
+            Self::Cup => "cup",
+            Self::Pint => "pt",
+            Self::Quart => "qt",
+            Self::Gallon => "gal",
+        }
+    }
+}
+
+impl FromStr for VolumeUnit
+{
+    type Err = ParseUnitError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        Ok(match &*s.trim().to_lowercase()
+        {
+            "ml" | "millilitre" | "millilitres" | "milliliter" | "milliliters" => Self::Millilitre,
+            "l" | "litre" | "litres" | "liter" | "liters" => Self::Litre,
+            "tsp" | "teaspoon" | "teaspoons" => Self::Teaspoon,
+            "tbsp" | "tablespoon" | "tablespoons" => Self::Tablespoon,
+            "fl oz" | "floz" | "fluid ounce" | "fluid ounces" => Self::FluidOunce,
+            "cup" | "cups" => Self::Cup,
+            "pt" | "pint" | "pints" => Self::Pint,
+            "qt" | "quart" | "quarts" => Self::Quart,
+            "gal" | "gallon" | "gallons" => Self::Gallon,
+            _ => return Err(ParseUnitError::InvalidUnit(s.trim().to_string())),
+        })
+    }
+}
+
+impl fmt::Display for VolumeUnit
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        write!(f, "{}", self.name(Locale::English))
+    }
+}